@@ -0,0 +1,233 @@
+use common::solution::{Puzzle, Timings};
+use serde_json::{json, Value};
+use std::time::Duration;
+
+pub const STAGES: [&str; 3] = ["parse", "part1", "part2"];
+
+/// Slowdowns smaller than this are timer noise, whatever the percentage.
+const NOISE: Duration = Duration::from_micros(50);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Summary {
+    pub median: Duration,
+    pub min: Duration,
+    pub max: Duration,
+}
+
+impl Summary {
+    pub fn of(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+        let n = sorted.len();
+        let median = match n {
+            0 => return None,
+            _ if n % 2 == 1 => sorted[n / 2],
+            _ => (sorted[n / 2 - 1] + sorted[n / 2]) / 2,
+        };
+        Some(Self {
+            median,
+            min: sorted[0],
+            max: sorted[n - 1],
+        })
+    }
+}
+
+/// The timings of one stage of one day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Row {
+    pub day: u8,
+    pub stage: &'static str,
+    pub summary: Summary,
+}
+
+/// Times each stage of `puzzle` over `samples` runs, after `warmup` runs
+/// that are thrown away.
+pub fn bench(
+    puzzle: &Puzzle,
+    input: &str,
+    warmup: usize,
+    samples: usize,
+) -> Vec<Row> {
+    for _ in 0..warmup {
+        puzzle.time(input);
+    }
+    let runs = (0..samples)
+        .map(|_| puzzle.time(input))
+        .collect::<Vec<Timings>>();
+    let stages: [Vec<Duration>; 3] = [
+        runs.iter().map(|t| t.parse).collect(),
+        runs.iter().map(|t| t.part1).collect(),
+        runs.iter().filter_map(|t| t.part2).collect(),
+    ];
+    STAGES
+        .iter()
+        .zip(stages)
+        .filter_map(|(&stage, samples)| {
+            Some(Row {
+                day: puzzle.day,
+                stage,
+                summary: Summary::of(&samples)?,
+            })
+        })
+        .collect()
+}
+
+/// Durations are whole nanoseconds, so a report reads back exactly.
+pub fn to_json(rows: &[Row]) -> String {
+    let nanos = |d: Duration| d.as_nanos() as u64;
+    let rows = rows
+        .iter()
+        .map(|row| {
+            json!({
+                "day": row.day,
+                "stage": row.stage,
+                "median_ns": nanos(row.summary.median),
+                "min_ns": nanos(row.summary.min),
+                "max_ns": nanos(row.summary.max),
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&rows).unwrap() + "\n"
+}
+
+pub fn from_json(text: &str) -> Result<Vec<Row>, String> {
+    let rows =
+        serde_json::from_str::<Vec<Value>>(text).map_err(|e| e.to_string())?;
+    rows.iter()
+        .map(|row| {
+            let nanos = |key: &str| {
+                row[key].as_u64().map(Duration::from_nanos).ok_or(format!(
+                    "{key} must be a whole number of nanoseconds"
+                ))
+            };
+            let stage = row["stage"]
+                .as_str()
+                .and_then(|name| STAGES.iter().find(|&&s| s == name))
+                .ok_or(format!("unknown stage {}", row["stage"]))?;
+            Ok(Row {
+                day: row["day"]
+                    .as_u64()
+                    .and_then(|day| u8::try_from(day).ok())
+                    .ok_or(format!("not a day: {}", row["day"]))?,
+                stage,
+                summary: Summary {
+                    median: nanos("median_ns")?,
+                    min: nanos("min_ns")?,
+                    max: nanos("max_ns")?,
+                },
+            })
+        })
+        .collect()
+}
+
+/// A table in the form the README uses.
+pub fn markdown(rows: &[Row]) -> String {
+    let mut table = String::from(
+        "| Day | Stage | Median | Min | Max |\n\
+         |----:|:------|-------:|----:|----:|\n",
+    );
+    for Row {
+        day,
+        stage,
+        summary,
+    } in rows
+    {
+        table += &format!(
+            "| {day} | {stage} | {:.2?} | {:.2?} | {:.2?} |\n",
+            summary.median, summary.min, summary.max
+        );
+    }
+    table
+}
+
+/// Stages whose median grew by more than `threshold` percent over the
+/// baseline. Stages missing from the baseline are new and never flagged.
+pub fn regressions(
+    baseline: &[Row],
+    rows: &[Row],
+    threshold: f64,
+) -> Vec<String> {
+    rows.iter()
+        .filter_map(|row| {
+            let before = baseline
+                .iter()
+                .find(|b| (b.day, b.stage) == (row.day, row.stage))?
+                .summary
+                .median;
+            let after = row.summary.median;
+            let change =
+                (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+            (change > threshold && after > before + NOISE).then(|| {
+                format!(
+                    "day {} {}: {before:.2?} -> {after:.2?} (+{change:.0}%)",
+                    row.day, row.stage
+                )
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(n: u64) -> Duration {
+        Duration::from_millis(n)
+    }
+
+    fn row(day: u8, stage: &'static str, median: u64) -> Row {
+        Row {
+            day,
+            stage,
+            summary: Summary {
+                median: ms(median),
+                min: ms(median - 1),
+                max: ms(median + 2),
+            },
+        }
+    }
+
+    #[test]
+    fn test_summary() {
+        let odd = Summary::of(&[ms(5), ms(1), ms(3)]).unwrap();
+        assert_eq!((odd.median, odd.min, odd.max), (ms(3), ms(1), ms(5)));
+        let even = Summary::of(&[ms(4), ms(1), ms(2), ms(9)]).unwrap();
+        assert_eq!(even.median, ms(3));
+        assert_eq!(Summary::of(&[]), None);
+    }
+
+    #[test]
+    fn test_reports() {
+        let rows = [row(3, "parse", 1), row(3, "part1", 12)];
+        assert_eq!(from_json(&to_json(&rows)), Ok(rows.to_vec()));
+        assert!(from_json(r#"[{"day": 3, "stage": "part3"}]"#).is_err());
+        assert_eq!(
+            markdown(&rows[1..]),
+            "| Day | Stage | Median | Min | Max |\n\
+             |----:|:------|-------:|----:|----:|\n\
+             | 3 | part1 | 12.00ms | 11.00ms | 14.00ms |\n"
+        );
+    }
+
+    #[test]
+    fn test_regressions() {
+        let baseline = [row(3, "part1", 10), row(3, "part2", 10)];
+        let rows = [
+            row(3, "part1", 11),
+            row(3, "part2", 13),
+            row(4, "part1", 50),
+        ];
+        assert_eq!(
+            regressions(&baseline, &rows, 20.0),
+            ["day 3 part2: 10.00ms -> 13.00ms (+30%)"]
+        );
+        assert_eq!(regressions(&baseline, &rows, 5.0).len(), 2);
+
+        // a doubling of next to nothing is noise
+        let tiny = |nanos| Row {
+            summary: Summary::of(&[Duration::from_nanos(nanos)]).unwrap(),
+            ..row(1, "parse", 1)
+        };
+        assert!(regressions(&[tiny(20)], &[tiny(40)], 10.0).is_empty());
+    }
+}
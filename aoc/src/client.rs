@@ -0,0 +1,127 @@
+use std::time::Duration;
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+/// Identifies the tool to the site, as its maintainers ask automated
+/// clients to do.
+const USER_AGENT: &str = concat!(
+    "aoc/",
+    env!("CARGO_PKG_VERSION"),
+    " (+https://github.com/zh0uquan/adventofcode2025)"
+);
+
+/// An authenticated connection to the puzzle site.
+pub struct Client {
+    base: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base: &str, session: &str) -> Self {
+        Self {
+            base: base.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .user_agent(USER_AGENT)
+                .timeout(Duration::from_secs(30))
+                .build(),
+        }
+    }
+
+    /// Reads `SESSION` from the environment or a `.env` file, and
+    /// `AOC_BASE_URL` when the real site should not be used.
+    pub fn from_env() -> Result<Self, String> {
+        dotenvy::dotenv().ok();
+        let session = std::env::var("SESSION").map_err(|_| {
+            "SESSION is not set: copy the session cookie into .env".to_string()
+        })?;
+        let base = std::env::var("AOC_BASE_URL");
+        Ok(Self::new(base.as_deref().unwrap_or(BASE_URL), &session))
+    }
+
+    fn send(
+        &self,
+        request: ureq::Request,
+        form: Option<&[(&str, &str)]>,
+    ) -> Result<String, String> {
+        let request =
+            request.set("Cookie", &format!("session={}", self.session));
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+        match response {
+            Ok(response) => response
+                .into_string()
+                .map_err(|e| format!("cannot read the response: {e}")),
+            Err(ureq::Error::Status(code, response)) => {
+                let body = response.into_string().unwrap_or_default();
+                let reason = body.lines().next().unwrap_or("").trim();
+                Err(format!("the server answered {code}: {reason}"))
+            }
+            Err(e) => Err(format!("request failed: {e}")),
+        }
+    }
+
+    pub fn get(&self, path: &str) -> Result<String, String> {
+        let url = format!("{}{path}", self.base);
+        self.send(self.agent.get(&url), None)
+    }
+
+    pub fn post(
+        &self,
+        path: &str,
+        form: &[(&str, &str)],
+    ) -> Result<String, String> {
+        let url = format!("{}{path}", self.base);
+        self.send(self.agent.post(&url), Some(form))
+    }
+}
+
+/// A one-shot HTTP server on localhost for exercising the client.
+#[cfg(test)]
+pub mod stub {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::thread::JoinHandle;
+
+    /// Serves a single request with the given status and body, returning
+    /// the base URL to use and a handle yielding the raw request.
+    pub fn serve(status: u16, body: &str) -> (String, JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let body = body.to_string();
+        let handle = std::thread::spawn(move || {
+            let (stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream);
+            let mut request = String::new();
+            let mut length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        length = value.trim().parse().unwrap();
+                    }
+                }
+                request += &line;
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+            }
+            let mut content = vec![0; length];
+            reader.read_exact(&mut content).unwrap();
+            request += &String::from_utf8(content).unwrap();
+
+            let response = format!(
+                "HTTP/1.1 {status} Stub\r\nContent-Length: {}\r\n\
+                 Connection: close\r\n\r\n{body}",
+                body.len()
+            );
+            reader.get_mut().write_all(response.as_bytes()).unwrap();
+            request
+        });
+        (base, handle)
+    }
+}
@@ -0,0 +1,20 @@
+//! Written by `aoc new`, which lists every day crate here.
+
+use common::solution::Puzzle;
+
+pub fn puzzles() -> Vec<Puzzle> {
+    vec![
+        Puzzle::of::<day1::Day1>(),
+        Puzzle::of::<day2::Day2>(),
+        Puzzle::of::<day3::Day3>(),
+        Puzzle::of::<day4::Day4>(),
+        Puzzle::of::<day5::Day5>(),
+        Puzzle::of::<day6::Day6>(),
+        Puzzle::of::<day7::Day7>(),
+        Puzzle::of::<day8::Day8>(),
+        Puzzle::of::<day9::Day9>(),
+        Puzzle::of::<day10::Day10>(),
+        Puzzle::of::<day11::Day11>(),
+        Puzzle::of::<day12::Day12>(),
+    ]
+}
@@ -0,0 +1,148 @@
+use scraper::{ElementRef, Html, Node, Selector};
+
+/// Backslashes what markdown would otherwise read as formatting.
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    for c in text.chars() {
+        if matches!(c, '*' | '_' | '`' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
+fn text(element: ElementRef) -> String {
+    element.text().collect()
+}
+
+/// The inline content of a paragraph or list item, whitespace collapsed.
+fn inline(element: ElementRef) -> String {
+    let mut out = String::new();
+    for child in element.children() {
+        match child.value() {
+            Node::Text(t) => out += &escape(t),
+            Node::Element(e) => {
+                let child = ElementRef::wrap(child).unwrap();
+                match e.name() {
+                    // the page highlights answers as emphasised code
+                    "code" => {
+                        let code = format!("`{}`", text(child));
+                        let em = Selector::parse("em").unwrap();
+                        match child.select(&em).next() {
+                            Some(em) if text(em) == text(child) => {
+                                out += &format!("**{code}**")
+                            }
+                            _ => out += &code,
+                        }
+                    }
+                    "em" => match child.first_child().map(|c| c.value()) {
+                        Some(Node::Element(e)) if e.name() == "code" => {
+                            out += &format!("**`{}`**", text(child))
+                        }
+                        _ => out += &format!("*{}*", inline(child)),
+                    },
+                    "a" => {
+                        let href = e.attr("href").unwrap_or("");
+                        out += &format!("[{}]({href})", inline(child));
+                    }
+                    _ => out += &inline(child),
+                }
+            }
+            _ => {}
+        }
+    }
+    out.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// The blocks of one article, each followed by a blank line.
+fn blocks(article: ElementRef, heading: &str) -> String {
+    let mut out = String::new();
+    for child in article.children().filter_map(ElementRef::wrap) {
+        match child.value().name() {
+            "h2" => {
+                let title = text(child);
+                let title = title.trim_matches(|c| c == '-' || c == ' ');
+                out += &format!("{heading} {title}\n\n");
+            }
+            "pre" => {
+                let code = text(child);
+                let newline = if code.ends_with('\n') { "" } else { "\n" };
+                out += &format!("```\n{code}{newline}```\n\n");
+            }
+            "ul" | "ol" => {
+                for item in child.children().filter_map(ElementRef::wrap) {
+                    out += &format!("- {}\n", inline(item));
+                }
+                out += "\n";
+            }
+            _ => out += &format!("{}\n\n", inline(child)),
+        }
+    }
+    out
+}
+
+/// The puzzle text of a day's page as markdown: the day's title, part one
+/// and, once it is unlocked, part two.
+pub fn markdown(page: &str) -> Result<String, String> {
+    let html = Html::parse_document(page);
+    let articles = Selector::parse("article.day-desc").unwrap();
+    let markdown = html
+        .select(&articles)
+        .enumerate()
+        .map(|(i, article)| blocks(article, if i == 0 { "#" } else { "##" }))
+        .collect::<String>();
+    if markdown.is_empty() {
+        return Err("no puzzle description found in the page".into());
+    }
+    Ok(markdown.trim_end().to_string() + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_markdown() {
+        let page = indoc! {r#"
+            <html><body><main>
+            <article class="day-desc"><h2>--- Day 7: Laboratories ---</h2>
+            <p>The beam starts at <code>S</code> and
+               <em>splits</em> at each <code>^</code>, so 2*3 beams:</p>
+            <pre><code>..S..
+            ..<em>^</em>..
+            </code></pre>
+            <ul><li>One <a href="/2025/about">rule</a>.</li></ul>
+            <p>The beam is split <code><em>21</em></code> times.</p>
+            </article>
+            <p>Your puzzle answer was <code>1602</code>.</p>
+            <article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+            <p>Now there are <em><code>40</code></em> timelines.</p>
+            </article>
+            </main></body></html>
+        "#};
+        assert_eq!(
+            markdown(page).unwrap(),
+            indoc! {r#"
+                # Day 7: Laboratories
+
+                The beam starts at `S` and *splits* at each `^`, so 2\*3 beams:
+
+                ```
+                ..S..
+                ..^..
+                ```
+
+                - One [rule](/2025/about).
+
+                The beam is split **`21`** times.
+
+                ## Part Two
+
+                Now there are **`40`** timelines.
+            "#}
+        );
+        assert!(markdown("<html></html>").is_err());
+    }
+}
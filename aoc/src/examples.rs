@@ -0,0 +1,156 @@
+use scraper::{ElementRef, Html, Selector};
+use std::path::{Path, PathBuf};
+
+/// What a saved puzzle page shows: its example blocks in page order and
+/// the highlighted answer each part's description ends with.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Examples {
+    pub blocks: Vec<String>,
+    pub answers: Vec<(u8, String)>,
+}
+
+fn in_pre(element: &ElementRef) -> bool {
+    element.ancestors().any(|node| {
+        node.value().as_element().is_some_and(|e| e.name() == "pre")
+    })
+}
+
+/// Reads the examples out of a puzzle page. Each part is its own
+/// `article.day-desc`; part two only appears once part one is solved.
+pub fn extract(page: &str) -> Result<Examples, String> {
+    let html = Html::parse_document(page);
+    let selector = |css| Selector::parse(css).unwrap();
+    let (articles, blocks, highlights) = (
+        selector("article.day-desc"),
+        selector("pre > code"),
+        selector("code > em, em > code"),
+    );
+    let mut examples = Examples {
+        blocks: vec![],
+        answers: vec![],
+    };
+    for (part, article) in (1..).zip(html.select(&articles)) {
+        examples.blocks.extend(
+            article
+                .select(&blocks)
+                .map(|block| block.text().collect::<String>()),
+        );
+        // highlights inside a block mark cells of the example, not answers
+        let answer = article
+            .select(&highlights)
+            .filter(|highlight| !in_pre(highlight))
+            .last();
+        if let Some(answer) = answer {
+            examples.answers.push((part, answer.text().collect()));
+        }
+    }
+    if examples.blocks.is_empty() {
+        return Err("no example blocks found, is this a puzzle page?".into());
+    }
+    Ok(examples)
+}
+
+/// Writes the blocks as `1.txt`, `2.txt`, ... and the answers as
+/// `answers.toml` into `dir`, leaving any existing fixture untouched.
+pub fn write(examples: &Examples, dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut files = (1..)
+        .zip(&examples.blocks)
+        .map(|(n, block)| (dir.join(format!("{n}.txt")), block.as_str()))
+        .collect::<Vec<_>>();
+    let answers = dir.join("answers.toml");
+    let answered = !examples.answers.is_empty();
+    let existing = files
+        .iter()
+        .map(|(path, _)| path)
+        .chain(answered.then_some(&answers))
+        .find(|path| path.exists());
+    if let Some(path) = existing {
+        return Err(format!(
+            "{} already exists, remove the examples to extract them again",
+            path.display()
+        ));
+    }
+    std::fs::create_dir_all(dir)
+        .map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+    for (path, block) in &files {
+        std::fs::write(path, block)
+            .map_err(|e| format!("cannot write {}: {e}", path.display()))?;
+    }
+    if answered {
+        crate::verify::write_answers(&answers, &examples.answers)?;
+        files.push((answers, ""));
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    const PAGE: &str = indoc! {r#"
+        <!DOCTYPE html>
+        <html><body><main>
+        <article class="day-desc"><h2>--- Day 6: Trash Compactor ---</h2>
+        <p>For example:</p>
+        <pre><code>123 328  51 64
+         45 64  387 23
+          6 98  215 314
+        <em>*</em>   +   *   +
+        </code></pre>
+        <p>Adding these together produces the grand total of
+        <code><em>4277556</em></code>.</p>
+        </article>
+        <p>Your puzzle answer was <code>5361735137219</code>.</p>
+        <article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+        <p>Reading <code>a &lt; b &amp;&amp; c</code> right to left:</p>
+        <pre><code>&lt;-- 4 + 431 * 623</code></pre>
+        <p>the grand total is now <em><code>3263827</code></em>.</p>
+        </article>
+        </main></body></html>
+    "#};
+
+    #[test]
+    fn test_extract() {
+        let examples = extract(PAGE).unwrap();
+        assert_eq!(
+            examples.blocks,
+            [
+                "123 328  51 64\n 45 64  387 23\n  6 98  215 314\n\
+                 *   +   *   +\n",
+                "<-- 4 + 431 * 623",
+            ]
+        );
+        assert_eq!(
+            examples.answers,
+            [(1, "4277556".to_string()), (2, "3263827".to_string())]
+        );
+        assert!(extract("<html><body>Not Found</body></html>").is_err());
+    }
+
+    #[test]
+    fn test_write() {
+        let dir = std::env::temp_dir().join("aoc-examples-test");
+        std::fs::remove_dir_all(&dir).ok();
+        let examples = extract(PAGE).unwrap();
+        let written = write(&examples, &dir).unwrap();
+        assert_eq!(written.len(), 3);
+        let first = std::fs::read_to_string(dir.join("1.txt")).unwrap();
+        assert_eq!(first, examples.blocks[0]);
+        let answers = crate::verify::read_answers(&dir.join("answers.toml"));
+        assert_eq!(answers, Ok(Some(examples.answers.clone())));
+
+        let again = write(&examples, &dir).unwrap_err();
+        assert!(again.contains("remove the examples to extract them again"));
+
+        // answers kept from an earlier extraction are not overwritten either
+        for n in 1..=2 {
+            std::fs::remove_file(dir.join(format!("{n}.txt"))).unwrap();
+        }
+        let again = write(&examples, &dir).unwrap_err();
+        assert!(
+            again.starts_with(&dir.join("answers.toml").display().to_string())
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,94 @@
+use crate::client::Client;
+use std::path::Path;
+
+/// Rejects responses that are clearly not a puzzle input: the site answers
+/// a bad or expired session with a page or a one-line notice.
+fn validate(body: &str) -> Result<(), String> {
+    let start = body.trim_start().to_ascii_lowercase();
+    if start.starts_with("<!doctype") || start.starts_with("<html") {
+        return Err("the server sent an HTML page instead of an input, \
+                    check SESSION"
+            .into());
+    }
+    if body.trim().is_empty() {
+        return Err("the server sent an empty input".into());
+    }
+    if body.starts_with("Puzzle inputs differ by user") {
+        return Err("the session was not accepted, check SESSION".into());
+    }
+    Ok(())
+}
+
+/// Downloads the input for `day` of `year` into `target`, which must not
+/// exist yet or be the empty stub `aoc new` leaves.
+pub fn fetch(
+    client: &Client,
+    year: u16,
+    day: u8,
+    target: &Path,
+) -> Result<(), String> {
+    if target.metadata().is_ok_and(|m| m.len() > 0) {
+        return Err(format!(
+            "{} already exists, remove it to fetch again",
+            target.display()
+        ));
+    }
+    let body = client.get(&format!("/{year}/day/{day}/input"))?;
+    validate(&body)?;
+    if let Some(dir) = target.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+    }
+    std::fs::write(target, body)
+        .map_err(|e| format!("cannot write {}: {e}", target.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub;
+
+    fn target(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-fetch-{name}"));
+        std::fs::remove_dir_all(&dir).ok();
+        dir.join("input.txt")
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (base, request) = stub::serve(200, "1,2\n3,4\n");
+        let client = Client::new(&base, "cafe\n");
+        let target = target("ok");
+        std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+        std::fs::write(&target, "").unwrap();
+        fetch(&client, 2024, 3, &target).unwrap();
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "1,2\n3,4\n");
+
+        let request = request.join().unwrap();
+        assert!(request.starts_with("GET /2024/day/3/input HTTP/1.1"));
+        assert!(request.contains("Cookie: session=cafe\r\n"));
+        assert!(request.contains("User-Agent: aoc/"));
+
+        // a second fetch never reaches the server
+        let error = fetch(&client, 2024, 3, &target).unwrap_err();
+        assert!(error.ends_with("already exists, remove it to fetch again"));
+    }
+
+    #[test]
+    fn test_rejected() {
+        let (base, _) = stub::serve(200, "<!DOCTYPE html>\n<html></html>");
+        let target = target("html");
+        let error =
+            fetch(&Client::new(&base, "x"), 2025, 1, &target).unwrap_err();
+        assert!(error.contains("HTML page"));
+        assert!(!target.exists());
+
+        let (base, _) = stub::serve(400, "Puzzle inputs differ by user.");
+        let error =
+            fetch(&Client::new(&base, "x"), 2025, 1, &target).unwrap_err();
+        assert_eq!(
+            error,
+            "the server answered 400: Puzzle inputs differ by user."
+        );
+    }
+}
@@ -0,0 +1,305 @@
+use std::fmt::{Display, Formatter};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    Wrong,
+    /// Submitted too soon after the last attempt; nothing was judged.
+    Wait(u64),
+    /// The part was already solved, so nothing was judged.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the page the site answers a submission
+    /// with.
+    pub fn parse(page: &str) -> Option<Self> {
+        if page.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if page.contains("You gave an answer too recently") {
+            let left =
+                page.split("You have ").nth(1)?.split(" left").next()?;
+            Some(Verdict::Wait(seconds(left)?))
+        } else if page.contains("Did you already complete it") {
+            Some(Verdict::AlreadySolved)
+        } else if page.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if page.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if page.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else {
+            None
+        }
+    }
+}
+
+/// `1m 5s` or `39s` as seconds.
+fn seconds(text: &str) -> Option<u64> {
+    text.split_whitespace()
+        .map(|part| match part.strip_suffix('m') {
+            Some(n) => n.parse::<u64>().ok().map(|n| n * 60),
+            None => part.strip_suffix('s')?.parse::<u64>().ok(),
+        })
+        .sum()
+}
+
+/// Seconds since the epoch, the clock attempts are recorded by.
+pub fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too-high"),
+            Verdict::TooLow => write!(f, "too-low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait(secs) => write!(f, "wait {secs}s"),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "too-high" => Ok(Verdict::TooHigh),
+            "too-low" => Ok(Verdict::TooLow),
+            "wrong" => Ok(Verdict::Wrong),
+            _ => s
+                .strip_prefix("wait ")
+                .and_then(seconds)
+                .map(Verdict::Wait)
+                .ok_or(format!("unknown verdict {s:?}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// When the site answered, in seconds since the epoch.
+    pub at: u64,
+}
+
+/// Every judged submission, one tab-separated line each, so answers the
+/// site has already ruled out are never sent again. Requests to wait are
+/// kept as well, so nothing is sent before the wait is over.
+pub struct Ledger {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl Ledger {
+    /// Reads the ledger at `path`; a missing file is an empty ledger.
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let text = match std::fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                String::new()
+            }
+            Err(e) => {
+                return Err(format!("cannot read {}: {e}", path.display()))
+            }
+        };
+        let attempts = text
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                parse_attempt(line).ok_or(format!(
+                    "{}:{}: malformed entry",
+                    path.display(),
+                    i + 1
+                ))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    /// Refuses answers the ledger already settles: the part is solved, the
+    /// same answer was wrong, or it lies outside a known bound. Also
+    /// refuses any answer at `now` while the site still asks to wait.
+    pub fn check(
+        &self,
+        (year, day, part): (u16, u8, u8),
+        answer: &str,
+        now: u64,
+    ) -> Result<(), String> {
+        let attempts = self
+            .attempts
+            .iter()
+            .filter(|a| (a.year, a.day, a.part) == (year, day, part));
+        let number = answer.parse::<i128>().ok();
+        for attempt in attempts {
+            let known = attempt.answer.parse::<i128>().ok();
+            let refused = match attempt.verdict {
+                Verdict::Wait(secs) if now < attempt.at + secs => {
+                    return Err(format!(
+                        "the site asked to wait, {}s left",
+                        attempt.at + secs - now
+                    ))
+                }
+                Verdict::Wait(_) => false,
+                Verdict::Correct => {
+                    return Err(format!(
+                        "already solved with {}",
+                        attempt.answer
+                    ))
+                }
+                _ if attempt.answer == answer => true,
+                Verdict::TooHigh => number >= known && known.is_some(),
+                Verdict::TooLow => number <= known && number.is_some(),
+                _ => false,
+            };
+            if refused {
+                return Err(format!(
+                    "{answer} is ruled out: {} was {}",
+                    attempt.answer, attempt.verdict
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Appends an attempt; repeats of solved parts carry no information
+    /// and are skipped.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), String> {
+        if attempt.verdict == Verdict::AlreadySolved {
+            return Ok(());
+        }
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(|e| {
+                format!("cannot open {}: {e}", self.path.display())
+            })?;
+        writeln!(
+            file,
+            "{}\t{}\t{}\t{}\t{}\t{}",
+            attempt.year,
+            attempt.day,
+            attempt.part,
+            attempt.answer,
+            attempt.verdict,
+            attempt.at
+        )
+        .map_err(|e| format!("cannot write {}: {e}", self.path.display()))?;
+        self.attempts.push(attempt);
+        Ok(())
+    }
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let mut fields = line.split('\t');
+    let attempt = Attempt {
+        year: fields.next()?.parse().ok()?,
+        day: fields.next()?.parse().ok()?,
+        part: fields.next()?.parse().ok()?,
+        answer: fields.next()?.to_string(),
+        verdict: fields.next()?.parse().ok()?,
+        // entries from before times were kept have none
+        at: fields.next().map_or(Some(0), |at| at.parse().ok())?,
+    };
+    fields.next().is_none().then_some(attempt)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verdicts() {
+        let page = |text: &str| format!("<main><article><p>{text}</p>");
+        assert_eq!(
+            Verdict::parse(&page("That's the right answer!  You are one")),
+            Some(Verdict::Correct)
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(
+            Verdict::parse(&page("That's not the right answer.  If")),
+            Some(Verdict::Wrong)
+        );
+        assert_eq!(
+            Verdict::parse(&page(
+                "You gave an answer too recently; you have to wait after \
+                 submitting an answer before trying again.  You have 1m 5s \
+                 left to wait."
+            )),
+            Some(Verdict::Wait(65))
+        );
+        assert_eq!("wait 65s".parse(), Ok(Verdict::Wait(65)));
+        // a unit that is not one byte long is no time at all
+        assert_eq!(seconds("5µ"), None);
+        assert_eq!(seconds("1m 5s"), Some(65));
+        assert_eq!(Verdict::parse("<html>"), None);
+    }
+
+    #[test]
+    fn test_ledger() {
+        let path = std::env::temp_dir().join("aoc-ledger-test.tsv");
+        std::fs::remove_file(&path).ok();
+        let mut ledger = Ledger::open(&path).unwrap();
+        let attempt = |answer: &str, verdict| Attempt {
+            year: 2025,
+            day: 3,
+            part: 1,
+            answer: answer.to_string(),
+            verdict,
+            at: 1000,
+        };
+        ledger.record(attempt("500", Verdict::TooHigh)).unwrap();
+        ledger.record(attempt("100", Verdict::TooLow)).unwrap();
+        ledger.record(attempt("300", Verdict::Wrong)).unwrap();
+        ledger.record(attempt("250", Verdict::Wait(30))).unwrap();
+        ledger.record(attempt("1", Verdict::AlreadySolved)).unwrap();
+
+        let ledger = Ledger::open(&path).unwrap();
+        assert_eq!(ledger.attempts.len(), 4);
+        assert_eq!(ledger.attempts[3].verdict, Verdict::Wait(30));
+        let later = 1030;
+        assert!(ledger.check((2025, 3, 1), "250", later).is_ok());
+        assert!(ledger.check((2025, 3, 2), "300", later).is_ok());
+        assert_eq!(
+            ledger.check((2025, 3, 1), "250", 1010),
+            Err("the site asked to wait, 20s left".into())
+        );
+        assert_eq!(
+            ledger.check((2025, 3, 1), "600", later),
+            Err("600 is ruled out: 500 was too-high".into())
+        );
+        assert!(ledger.check((2025, 3, 1), "100", later).is_err());
+        assert!(ledger.check((2025, 3, 1), "300", later).is_err());
+
+        // entries written before attempts carried a time still read
+        std::fs::write(&path, "2025\t3\t1\t500\ttoo-high\n").unwrap();
+        assert_eq!(Ledger::open(&path).unwrap().attempts[0].at, 0);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,451 @@
+use client::Client;
+use common::input;
+use common::solution::Puzzle;
+use days::puzzles;
+use std::process::ExitCode;
+use std::time::Instant;
+
+mod bench;
+mod client;
+mod days;
+mod describe;
+mod examples;
+mod fetch;
+mod ledger;
+mod new;
+mod submit;
+mod verify;
+
+const USAGE: &str = "usage:
+    aoc run <day|all> [input|-] [--part <1|2>]
+    aoc new <day>
+    aoc fetch <day>
+    aoc examples <day> <saved page>
+    aoc describe <day> [saved page]
+    aoc submit <day> <1|2> [input|-]
+    aoc verify [day] [--record]
+    aoc bench [day] [--samples <n>] [--warmup <n>] [--json <file>]
+              [--markdown <file>] [--baseline <file>] [--threshold <pct>]
+every command takes --year <year>, 2025 by default";
+
+/// The value following `name` in `args`.
+fn option<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    let i = args.iter().position(|arg| arg == name)?;
+    args.get(i + 1).map(String::as_str)
+}
+
+/// `--year`, or the calendar at the top of the workspace.
+fn year(args: &[String]) -> Result<u16, String> {
+    match option(args, "--year") {
+        Some(year) => year.parse().map_err(|_| format!("not a year: {year}")),
+        None if args.iter().any(|arg| arg == "--year") => {
+            Err("--year takes a year".into())
+        }
+        None => Ok(input::YEAR),
+    }
+}
+
+/// The solved days of one year.
+fn calendar(year: u16) -> Vec<Puzzle> {
+    puzzles()
+        .into_iter()
+        .filter(|puzzle| puzzle.year == year)
+        .collect()
+}
+
+/// Accepts `7` as well as the crate names `day7` and `day07`.
+fn parse_day(arg: &str) -> Result<u8, String> {
+    arg.trim_start_matches("day")
+        .parse::<u8>()
+        .ok()
+        .filter(|day| (1..=25).contains(day))
+        .ok_or(format!("not a day: {arg}\n{USAGE}"))
+}
+
+struct Selection<'a> {
+    puzzles: Vec<Puzzle>,
+    parts: Vec<u8>,
+    input: Option<&'a str>,
+}
+
+/// The days, parts and input selected by the arguments of `aoc run`.
+fn select<'a>(
+    args: &'a [String],
+    puzzles: &[Puzzle],
+) -> Result<Selection<'a>, String> {
+    let target = args.first().ok_or(USAGE)?;
+    let input = input::path_arg(&args[1..], &["--part", "--year"]);
+    let parts = if args.iter().any(|arg| arg == "--part") {
+        let part = option(args, "--part")
+            .and_then(|part| part.parse::<u8>().ok())
+            .filter(|part| (1..=2).contains(part))
+            .ok_or("--part takes 1 or 2")?;
+        vec![part]
+    } else {
+        vec![1, 2]
+    };
+    if target == "all" {
+        if input.is_some() {
+            return Err("an input can only be given for a single day".into());
+        }
+        return Ok(Selection {
+            puzzles: puzzles.to_vec(),
+            parts,
+            input,
+        });
+    }
+    let day = parse_day(target)?;
+    let puzzle = puzzles
+        .iter()
+        .find(|puzzle| puzzle.day == day)
+        .ok_or(format!("day {day} has no solution"))?;
+    Ok(Selection {
+        puzzles: vec![*puzzle],
+        parts,
+        input,
+    })
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    let selection = select(args, &calendar(year(args)?))?;
+    let mut failed = 0;
+    for puzzle in selection.puzzles {
+        let input = input::load(puzzle.year, puzzle.day, selection.input)
+            .map_err(|e| e.to_string())?;
+        let answers = puzzle.answers(&input, &selection.parts);
+        if answers.is_empty() {
+            println!("day {}: only has {} part", puzzle.day, puzzle.parts);
+        }
+        for (part, answer) in answers {
+            match answer {
+                Ok(answer) => {
+                    println!("day {} part {part}: {answer}", puzzle.day)
+                }
+                Err(e) => {
+                    failed += 1;
+                    println!("day {} part {part}: FAILED, {e}", puzzle.day);
+                }
+            }
+        }
+    }
+    match failed {
+        0 => Ok(()),
+        n => Err(format!("{n} part(s) could not be answered")),
+    }
+}
+
+/// Scaffolds the crate for a new day and adds it to the runner.
+fn new(args: &[String]) -> Result<(), String> {
+    let day = parse_day(args.first().ok_or(USAGE)?)?;
+    let root = input::root();
+    for path in new::new(&root, year(args)?, day)? {
+        let path = path.strip_prefix(&root).unwrap_or(&path);
+        println!("wrote {}", path.display());
+    }
+    Ok(())
+}
+
+fn fetch(args: &[String]) -> Result<(), String> {
+    let day = parse_day(args.first().ok_or(USAGE)?)?;
+    let year = year(args)?;
+    let target = input::default_path(year, day);
+    fetch::fetch(&Client::from_env()?, year, day, &target)?;
+    println!("saved {}", target.display());
+    Ok(())
+}
+
+/// Turns the examples of a puzzle page saved from the browser into the
+/// fixtures the day's tests read.
+fn examples(args: &[String]) -> Result<(), String> {
+    let day = parse_day(args.first().ok_or(USAGE)?)?;
+    let page = input::path_arg(&args[1..], &["--year"]).ok_or(USAGE)?;
+    let page = std::fs::read_to_string(page)
+        .map_err(|e| format!("cannot read {page}: {e}"))?;
+    let examples = examples::extract(&page)?;
+    for path in
+        examples::write(&examples, &input::examples_dir(year(args)?, day))?
+    {
+        println!("wrote {}", path.display());
+    }
+    for (part, answer) in &examples.answers {
+        println!("part {part} example answer: {answer}");
+    }
+    Ok(())
+}
+
+/// Writes the puzzle text into the day's crate as `PUZZLE.md`, from a saved
+/// page or else from the site, where part two needs `SESSION`.
+fn describe(args: &[String]) -> Result<(), String> {
+    let day = parse_day(args.first().ok_or(USAGE)?)?;
+    let year = year(args)?;
+    let dir = input::root().join(new::crate_dir(year, day));
+    if !dir.exists() {
+        return Err(format!(
+            "{} does not exist, see `aoc new`",
+            dir.display()
+        ));
+    }
+    let page = match input::path_arg(&args[1..], &["--year"]) {
+        Some(page) => std::fs::read_to_string(page)
+            .map_err(|e| format!("cannot read {page}: {e}"))?,
+        None => Client::from_env()?.get(&format!("/{year}/day/{day}"))?,
+    };
+    let target = dir.join("PUZZLE.md");
+    std::fs::write(&target, describe::markdown(&page)?)
+        .map_err(|e| format!("cannot write {}: {e}", target.display()))?;
+    println!("wrote {}", target.display());
+    Ok(())
+}
+
+/// Solves one part and hands the answer to the site, checking the ledger
+/// in `inputs/` first.
+fn submit(args: &[String]) -> Result<(), String> {
+    let day = parse_day(args.first().ok_or(USAGE)?)?;
+    let part = args
+        .get(1)
+        .and_then(|part| part.parse::<u8>().ok())
+        .filter(|part| (1..=2).contains(part))
+        .ok_or(USAGE)?;
+    let year = year(args)?;
+    let puzzle = calendar(year)
+        .into_iter()
+        .find(|puzzle| puzzle.day == day)
+        .ok_or(format!("day {day} of {year} has no solution"))?;
+    let input = input::path_arg(&args[2..], &["--year"]);
+    let input = input::load(year, day, input).map_err(|e| e.to_string())?;
+    let (_, answer) = puzzle
+        .answers(&input, &[part])
+        .pop()
+        .ok_or(format!("day {day} has no part {part}"))?;
+    let answer = answer.map_err(|e| format!("day {day} part {part}: {e}"))?;
+
+    let mut ledger =
+        ledger::Ledger::open(&input::root().join("inputs/ledger.tsv"))?;
+    let verdict = submit::submit(
+        &Client::from_env()?,
+        &mut ledger,
+        (year, day, part),
+        &answer,
+    )?;
+    println!("day {day} part {part}: {answer} is {verdict}");
+    Ok(())
+}
+
+/// Runs the days on their real inputs and compares the answers with the
+/// `answers.toml` beside each input.
+fn verify(args: &[String]) -> Result<(), String> {
+    let record = args.iter().any(|arg| arg == "--record");
+    let only = input::path_arg(args, &["--year"]);
+    let only = only.map(parse_day).transpose()?;
+    let year = year(args)?;
+    let mut changed = 0;
+    for puzzle in calendar(year) {
+        let day = puzzle.day;
+        if only.is_some_and(|only| only != day) {
+            continue;
+        }
+        let input = match input::load(year, day, None) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("day {day}: skipped, {e}");
+                continue;
+            }
+        };
+        let started = Instant::now();
+        let answers = verify::answers(&puzzle, &input);
+        let elapsed = started.elapsed();
+        let answers = match answers {
+            Ok(answers) => answers,
+            Err(e) => {
+                changed += 1;
+                println!("day {day}: FAILED ({elapsed:.2?})");
+                println!("  {e}");
+                continue;
+            }
+        };
+        let (actual, failures): (Vec<_>, Vec<_>) = answers
+            .into_iter()
+            .map(|(part, answer)| {
+                answer.map(|a| (part, a)).map_err(|e| (part, e))
+            })
+            .partition(Result::is_ok);
+        if !failures.is_empty() {
+            changed += 1;
+            println!("day {day}: FAILED ({elapsed:.2?})");
+            for (part, e) in failures.into_iter().filter_map(Result::err) {
+                println!("  part {part}: {e}");
+            }
+            continue;
+        }
+        let actual = actual.into_iter().flatten().collect::<Vec<_>>();
+
+        let path = input::dir(year, day).join("answers.toml");
+        // the stub `aoc new` leaves has no answers yet
+        let expected = verify::read_answers(&path)?.filter(|a| !a.is_empty());
+        match expected {
+            Some(expected) => {
+                let diff = verify::diff(&expected, &actual);
+                if diff.is_empty() {
+                    println!("day {day}: ok ({elapsed:.2?})");
+                } else {
+                    changed += 1;
+                    println!("day {day}: MISMATCH ({elapsed:.2?})");
+                    for line in diff {
+                        println!("  {line}");
+                    }
+                }
+            }
+            None if record => {
+                verify::write_answers(&path, &actual)?;
+                println!("day {day}: recorded ({elapsed:.2?})");
+            }
+            None => println!("day {day}: no answers recorded ({elapsed:.2?})"),
+        }
+    }
+    match changed {
+        0 => Ok(()),
+        n => Err(format!(
+            "{n} day(s) failed or no longer give the recorded answers"
+        )),
+    }
+}
+
+/// The number following `name`, or `default` when it is not given.
+fn number<T: std::str::FromStr>(
+    args: &[String],
+    name: &str,
+    default: T,
+) -> Result<T, String> {
+    match option(args, name) {
+        Some(value) => value
+            .parse()
+            .map_err(|_| format!("{name} takes a number, not {value}")),
+        None if args.iter().any(|arg| arg == name) => {
+            Err(format!("{name} takes a number"))
+        }
+        None => Ok(default),
+    }
+}
+
+/// Times every stage of the days on their real inputs, printing a table
+/// and failing when a baseline is given and something got slower.
+fn bench(args: &[String]) -> Result<(), String> {
+    let valued = [
+        "--samples",
+        "--warmup",
+        "--json",
+        "--markdown",
+        "--baseline",
+        "--threshold",
+        "--year",
+    ];
+    let only = input::path_arg(args, &valued).map(parse_day).transpose()?;
+    let samples = number(args, "--samples", 5)?.max(1);
+    let warmup = number(args, "--warmup", 1)?;
+    let threshold = number(args, "--threshold", 10.0)?;
+    let baseline = option(args, "--baseline")
+        .map(|path| {
+            std::fs::read_to_string(path)
+                .map_err(|e| format!("cannot read {path}: {e}"))
+                .and_then(|text| {
+                    bench::from_json(&text).map_err(|e| format!("{path}: {e}"))
+                })
+        })
+        .transpose()?;
+
+    let mut rows = vec![];
+    for puzzle in calendar(year(args)?) {
+        let day = puzzle.day;
+        if only.is_some_and(|only| only != day) {
+            continue;
+        }
+        match input::load(puzzle.year, day, None) {
+            Ok(input) => {
+                eprintln!("day {day}: {samples} samples");
+                rows.extend(bench::bench(&puzzle, &input, warmup, samples));
+            }
+            Err(e) => eprintln!("day {day}: skipped, {e}"),
+        }
+    }
+
+    let table = bench::markdown(&rows);
+    print!("{table}");
+    let write = |path: &str, text: &str| {
+        std::fs::write(path, text)
+            .map_err(|e| format!("cannot write {path}: {e}"))
+    };
+    if let Some(path) = option(args, "--markdown") {
+        write(path, &table)?;
+    }
+    if let Some(path) = option(args, "--json") {
+        write(path, &bench::to_json(&rows))?;
+    }
+    let slower =
+        bench::regressions(&baseline.unwrap_or_default(), &rows, threshold);
+    for line in &slower {
+        println!("slower: {line}");
+    }
+    match slower.len() {
+        0 => Ok(()),
+        n => Err(format!(
+            "{n} stage(s) slower than the baseline by over {threshold}%"
+        )),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("new") => new(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("examples") => examples(&args[1..]),
+        Some("describe") => describe(&args[1..]),
+        Some("submit") => submit(&args[1..]),
+        Some("verify") => verify(&args[1..]),
+        Some("bench") => bench(&args[1..]),
+        _ => Err(USAGE.to_string()),
+    };
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("{e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(line: &str) -> Vec<String> {
+        line.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_select() {
+        let puzzles = calendar(input::YEAR);
+        let line = args("all");
+        let all = select(&line, &puzzles).unwrap();
+        assert_eq!(all.puzzles.len(), puzzles.len());
+        assert_eq!(all.parts, [1, 2]);
+
+        let line = args("3 --part 2 mine.txt");
+        let one = select(&line, &puzzles).unwrap();
+        assert_eq!((one.puzzles[0].day, one.parts), (3, vec![2]));
+        assert_eq!(one.input, Some("mine.txt"));
+        let line = args("3 --year 2025 mine.txt");
+        assert_eq!(select(&line, &puzzles).unwrap().input, Some("mine.txt"));
+        assert_eq!(year(&line), Ok(2025));
+        assert_eq!(year(&args("3")), Ok(input::YEAR));
+        assert!(year(&args("3 --year")).is_err());
+
+        assert!(select(&args("all mine.txt"), &puzzles).is_err());
+        assert!(select(&args("3 --part 3"), &puzzles).is_err());
+        assert!(select(&args("3"), &puzzles[..2]).is_err());
+        assert!(select(&args("26"), &puzzles).is_err());
+        assert!(select(&args("seven"), &puzzles).is_err());
+        assert!(select(&[], &puzzles).is_err());
+    }
+}
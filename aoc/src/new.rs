@@ -0,0 +1,264 @@
+use common::input;
+use std::path::{Path, PathBuf};
+
+const CARGO_TOML: &str = include_str!("../templates/Cargo.toml.in");
+const LIB_RS: &str = include_str!("../templates/lib.rs.in");
+const MAIN_RS: &str = include_str!("../templates/main.rs.in");
+
+/// Stands in for answers until `aoc verify --record` fills them in.
+const ANSWERS_TOML: &str =
+    "# recorded by `aoc verify --record` once the day is solved\n";
+
+fn write(path: &Path, text: &str) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)
+            .map_err(|e| format!("cannot create {}: {e}", dir.display()))?;
+    }
+    std::fs::write(path, text)
+        .map_err(|e| format!("cannot write {}: {e}", path.display()))
+}
+
+/// Where a day's crate lives under the workspace root: this year's at the
+/// top, other years' as `yYYYY/dayDD`.
+pub fn crate_dir(year: u16, day: u8) -> String {
+    match year {
+        input::YEAR => format!("day{day}"),
+        _ => format!("y{year}/day{day:02}"),
+    }
+}
+
+/// Package names carry the year, as `day07` repeats across calendars.
+fn package(year: u16, day: u8) -> String {
+    crate_dir(year, day).replace('/', "-")
+}
+
+/// The year and day of a package named by `package`.
+fn parse_package(name: &str) -> Option<(u16, u8)> {
+    match name.strip_prefix('y') {
+        Some(rest) => {
+            let (year, day) = rest.split_once("-day")?;
+            Some((year.parse().ok()?, day.parse().ok()?))
+        }
+        None => Some((input::YEAR, name.strip_prefix("day")?.parse().ok()?)),
+    }
+}
+
+/// Creates the crate for `day` of `year` under `root`, stubs its input and
+/// answers and registers it with the runner. Returns the files written.
+pub fn new(root: &Path, year: u16, day: u8) -> Result<Vec<PathBuf>, String> {
+    let dir = root.join(crate_dir(year, day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+    let package = package(year, day);
+    let common = match year {
+        input::YEAR => "../common",
+        _ => "../../common",
+    };
+    let fill = |template: &str| {
+        template
+            .replace("{{package}}", &package)
+            .replace("{{lib}}", &package.replace('-', "_"))
+            .replace("{{common}}", common)
+            .replace("{{year}}", &year.to_string())
+            .replace("{{n}}", &day.to_string())
+    };
+    let mut files = vec![
+        (dir.join("Cargo.toml"), fill(CARGO_TOML)),
+        (dir.join("src/lib.rs"), fill(LIB_RS)),
+        (dir.join("src/main.rs"), fill(MAIN_RS)),
+    ];
+    // an empty input is left for `aoc fetch` to fill
+    let inputs = root.join(input::relative_dir(year, day));
+    for (name, text) in [("input.txt", ""), ("answers.toml", ANSWERS_TOML)] {
+        if !inputs.join(name).exists() {
+            files.push((inputs.join(name), text.to_string()));
+        }
+    }
+    for (path, text) in &files {
+        write(path, text)?;
+    }
+    let mut written = files.into_iter().map(|(path, _)| path).collect();
+    register(root, &mut written)?;
+    Ok(written)
+}
+
+/// The entries of `dir` that hold a crate, with their names.
+fn crates(dir: &Path) -> Result<Vec<(String, PathBuf)>, String> {
+    let entries = std::fs::read_dir(dir)
+        .map_err(|e| format!("cannot list {}: {e}", dir.display()))?;
+    Ok(entries
+        .flatten()
+        .filter_map(|entry| {
+            Some((entry.file_name().into_string().ok()?, entry.path()))
+        })
+        .collect())
+}
+
+/// The day crates present under `root`, by year and then day.
+fn days(root: &Path) -> Result<Vec<(u16, u8)>, String> {
+    let mut days = vec![];
+    for (name, path) in crates(root)? {
+        let year = name.strip_prefix('y').and_then(|y| y.parse().ok());
+        let (year, entries) = match year {
+            Some(year) => (year, crates(&path)?),
+            None => (input::YEAR, vec![(name, path)]),
+        };
+        days.extend(
+            entries
+                .iter()
+                .filter(|(_, path)| path.join("Cargo.toml").exists())
+                .filter_map(|(name, _)| name.strip_prefix("day")?.parse().ok())
+                .map(|day| (year, day)),
+        );
+    }
+    days.sort();
+    Ok(days)
+}
+
+/// Rewrites the runner's dependencies and day table to list every day
+/// crate in the workspace.
+fn register(root: &Path, written: &mut Vec<PathBuf>) -> Result<(), String> {
+    let days = days(root)?;
+    let manifest = root.join("aoc/Cargo.toml");
+    let text = std::fs::read_to_string(&manifest)
+        .map_err(|e| format!("cannot read {}: {e}", manifest.display()))?;
+    let mut lines = vec![];
+    for line in text.lines().filter(|line| !is_day_dependency(line)) {
+        lines.push(line.to_string());
+        if line.starts_with("common = ") {
+            lines.extend(days.iter().map(|&(year, day)| {
+                format!(
+                    "{} = {{ path = \"../{}\" }}",
+                    package(year, day),
+                    crate_dir(year, day)
+                )
+            }));
+        }
+    }
+    write(&manifest, &(lines.join("\n") + "\n"))?;
+
+    let table = root.join("aoc/src/days.rs");
+    write(&table, &days_rs(&days))?;
+    written.extend([manifest, table]);
+    add_members(root, &days, written)
+}
+
+/// Adds a workspace member per earlier calendar. Each is its own glob, as
+/// cargo rejects one that matches nothing.
+fn add_members(
+    root: &Path,
+    days: &[(u16, u8)],
+    written: &mut Vec<PathBuf>,
+) -> Result<(), String> {
+    let workspace = root.join("Cargo.toml");
+    let text = std::fs::read_to_string(&workspace)
+        .map_err(|e| format!("cannot read {}: {e}", workspace.display()))?;
+    let mut members = text.clone();
+    for &(year, _) in days.iter().filter(|(year, _)| *year != input::YEAR) {
+        let member = format!("\"y{year}/day*\"");
+        if !members.contains(&member) {
+            let (before, after) = members.split_once("\"day*\"").ok_or(
+                format!("{} has no \"day*\" member", workspace.display()),
+            )?;
+            members = format!("{before}\"day*\", {member}{after}");
+        }
+    }
+    if members != text {
+        write(&workspace, &members)?;
+        written.push(workspace);
+    }
+    Ok(())
+}
+
+fn is_day_dependency(line: &str) -> bool {
+    line.split_once(" = ")
+        .is_some_and(|(name, _)| parse_package(name).is_some())
+}
+
+fn days_rs(days: &[(u16, u8)]) -> String {
+    let entries = days
+        .iter()
+        .map(|&(year, day)| {
+            let lib = package(year, day).replace('-', "_");
+            format!("        Puzzle::of::<{lib}::Day{day}>(),\n")
+        })
+        .collect::<String>();
+    format!(
+        "//! Written by `aoc new`, which lists every day crate here.\n\
+         \n\
+         use common::solution::Puzzle;\n\
+         \n\
+         pub fn puzzles() -> Vec<Puzzle> {{\n    vec![\n{entries}    ]\n}}\n"
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let root = std::env::temp_dir().join("aoc-new-test");
+        std::fs::remove_dir_all(&root).ok();
+        write(
+            &root.join("aoc/Cargo.toml"),
+            "[dependencies]\ncommon = { path = \"../common\" }\n\
+             day2 = { path = \"../day2\" }\nureq = \"2\"\n",
+        )
+        .unwrap();
+        write(&root.join("day2/Cargo.toml"), "").unwrap();
+        let workspace = "[workspace]\nmembers = [\"day*\", \"common\"]\n";
+        write(&root.join("Cargo.toml"), workspace).unwrap();
+        write(&root.join("inputs/day13/input.txt"), "1 2 3\n").unwrap();
+
+        let written = new(&root, input::YEAR, 13).unwrap();
+        assert_eq!(written.len(), 6);
+        let read = |path: &str| std::fs::read_to_string(root.join(path));
+        assert_eq!(
+            read("aoc/Cargo.toml").unwrap(),
+            "[dependencies]\ncommon = { path = \"../common\" }\n\
+             day2 = { path = \"../day2\" }\n\
+             day13 = { path = \"../day13\" }\nureq = \"2\"\n"
+        );
+        assert!(read("aoc/src/days.rs").unwrap().contains(
+            "Puzzle::of::<day2::Day2>(),\n        \
+                       Puzzle::of::<day13::Day13>(),"
+        ));
+        assert!(read("day13/src/lib.rs").unwrap().contains("DAY: u8 = 13;"));
+        assert!(read("day13/Cargo.toml").unwrap().contains("\"day13\""));
+        // an input already fetched is kept
+        assert_eq!(read("inputs/day13/input.txt").unwrap(), "1 2 3\n");
+        assert_eq!(read("inputs/day13/answers.toml").unwrap(), ANSWERS_TOML);
+
+        let again = new(&root, input::YEAR, 13).unwrap_err();
+        assert!(again.ends_with("day13 already exists"));
+
+        assert_eq!(read("Cargo.toml").unwrap(), workspace);
+        new(&root, 2017, 3).unwrap();
+        new(&root, 2017, 4).unwrap();
+        assert_eq!(
+            read("Cargo.toml").unwrap(),
+            "[workspace]\nmembers = [\"day*\", \"y2017/day*\", \"common\"]\n"
+        );
+        let lib = read("y2017/day03/src/lib.rs").unwrap();
+        assert!(lib.contains("const YEAR: u16 = 2017;"));
+        assert!(read("y2017/day03/src/main.rs")
+            .unwrap()
+            .contains("use y2017_day03::Day3;"));
+        assert!(read("y2017/day03/Cargo.toml")
+            .unwrap()
+            .contains("path = \"../../common\""));
+        assert!(read("inputs/2017/day3/input.txt").is_ok());
+        assert!(read("aoc/Cargo.toml").unwrap().contains(
+            "common = { path = \"../common\" }\n\
+             y2017-day03 = { path = \"../y2017/day03\" }\n\
+             y2017-day04 = { path = \"../y2017/day04\" }\n\
+             day2 = "
+        ));
+        assert!(read("aoc/src/days.rs")
+            .unwrap()
+            .contains("y2017_day03::Day3"));
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,60 @@
+use crate::client::Client;
+use crate::ledger::{self, Attempt, Ledger, Verdict};
+
+/// Posts `answer` for one part unless the ledger already rules it out, and
+/// records what the site made of it.
+pub fn submit(
+    client: &Client,
+    ledger: &mut Ledger,
+    (year, day, part): (u16, u8, u8),
+    answer: &str,
+) -> Result<Verdict, String> {
+    ledger.check((year, day, part), answer, ledger::now())?;
+    let page = client.post(
+        &format!("/{year}/day/{day}/answer"),
+        &[("level", &part.to_string()), ("answer", answer)],
+    )?;
+    let verdict = Verdict::parse(&page)
+        .ok_or("the response does not look like a verdict")?;
+    ledger.record(Attempt {
+        year,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+        at: ledger::now(),
+    })?;
+    Ok(verdict)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::stub;
+
+    #[test]
+    fn test_submit() {
+        let path = std::env::temp_dir().join("aoc-submit-test.tsv");
+        std::fs::remove_file(&path).ok();
+        let mut ledger = Ledger::open(&path).unwrap();
+
+        let (base, request) = stub::serve(
+            200,
+            "<article><p>That's not the right answer; your answer is too \
+             high.</p></article>",
+        );
+        let client = Client::new(&base, "cafe");
+        let verdict = submit(&client, &mut ledger, (2025, 7, 2), "42");
+        assert_eq!(verdict, Ok(Verdict::TooHigh));
+        let request = request.join().unwrap();
+        assert!(request.starts_with("POST /2025/day/7/answer HTTP/1.1"));
+        assert!(request.ends_with("level=2&answer=42"));
+
+        // the stub is gone, so only the ledger can answer this
+        let refused = submit(&client, &mut ledger, (2025, 7, 2), "43");
+        assert_eq!(refused, Err("43 is ruled out: 42 was too-high".into()));
+        let reopened = Ledger::open(&path).unwrap();
+        assert!(reopened.check((2025, 7, 2), "42", ledger::now()).is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
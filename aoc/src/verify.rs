@@ -0,0 +1,166 @@
+use common::solution::{Answers, Puzzle};
+use std::path::Path;
+use std::sync::Mutex;
+
+/// Where the last panic caught by `answers` happened and what it said.
+static PANIC: Mutex<Option<String>> = Mutex::new(None);
+
+/// Answers every part of `puzzle`, or where and why it panicked, so one
+/// broken day does not stop the others from being checked. The panic is
+/// reported here rather than by the default hook.
+pub fn answers(puzzle: &Puzzle, input: &str) -> Result<Answers, String> {
+    let hook = std::panic::take_hook();
+    std::panic::set_hook(Box::new(|info| {
+        let location = info.location().map_or(String::new(), |l| {
+            format!(" at {}:{}", l.file(), l.line())
+        });
+        let message = info.payload_as_str().unwrap_or("?");
+        *PANIC.lock().unwrap() =
+            Some(format!("panicked{location}: {message}"));
+    }));
+    let answers = std::panic::catch_unwind(|| puzzle.answers(input, &[1, 2]));
+    std::panic::set_hook(hook);
+    answers.map_err(|_| {
+        PANIC
+            .lock()
+            .unwrap()
+            .take()
+            .unwrap_or("panicked".to_string())
+    })
+}
+
+/// The recorded answers in an `answers.toml`, as `part1 = ...` and
+/// `part2 = ...`. Numbers and strings are both accepted.
+pub fn read_answers(path: &Path) -> Result<Option<Vec<(u8, String)>>, String> {
+    let text = match std::fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("cannot read {}: {e}", path.display())),
+    };
+    let table = text
+        .parse::<toml::Table>()
+        .map_err(|e| format!("{}: {e}", path.display()))?;
+    let mut answers = vec![];
+    for (key, value) in table {
+        let part = match key.as_str() {
+            "part1" => 1,
+            "part2" => 2,
+            _ => return Err(format!("{}: unknown key {key}", path.display())),
+        };
+        let answer = match value {
+            toml::Value::String(s) => s,
+            toml::Value::Integer(n) => n.to_string(),
+            other => {
+                return Err(format!(
+                    "{}: {key} must be a number or a string, not {other}",
+                    path.display()
+                ))
+            }
+        };
+        answers.push((part, answer));
+    }
+    answers.sort();
+    Ok(Some(answers))
+}
+
+/// Answers as written by `aoc verify --record`, always as strings so
+/// answers past `i64` survive.
+pub fn write_answers(
+    path: &Path,
+    answers: &[(u8, String)],
+) -> Result<(), String> {
+    let text = answers
+        .iter()
+        .map(|(part, answer)| format!("part{part} = {answer:?}\n"))
+        .collect::<String>();
+    std::fs::write(path, text)
+        .map_err(|e| format!("cannot write {}: {e}", path.display()))
+}
+
+/// The parts that differ, in unified diff style, followed by the parts
+/// answered now that `expected` has no record of.
+pub fn diff(
+    expected: &[(u8, String)],
+    actual: &[(u8, String)],
+) -> Vec<String> {
+    let mut lines = vec![];
+    for (part, want) in expected {
+        let got = actual.iter().find(|(p, _)| p == part).map(|(_, a)| a);
+        if got != Some(want) {
+            lines.push(format!("part {part}:"));
+            lines.push(format!("  - {want}"));
+            lines.push(format!("  + {}", got.map_or("(no answer)", |a| a)));
+        }
+    }
+    for (part, got) in actual {
+        if expected.iter().all(|(p, _)| p != part) {
+            lines.push(format!("part {part}: unrecorded"));
+            lines.push(format!("  + {got}"));
+        }
+    }
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::solution::Solution;
+
+    struct Broken;
+
+    impl Solution for Broken {
+        type Parsed<'a> = &'a str;
+        type Answer1 = usize;
+        type Answer2 = usize;
+
+        const DAY: u8 = 1;
+
+        fn parse(input: &str) -> &str {
+            input
+        }
+
+        fn part1(input: &&str) -> usize {
+            input.len()
+        }
+
+        fn part2(input: &&str) -> usize {
+            panic!("no part two for {input:?}")
+        }
+    }
+
+    #[test]
+    fn test_panic() {
+        let puzzle = Puzzle::of::<Broken>();
+        let e = answers(&puzzle, "ab").unwrap_err();
+        assert!(e.starts_with("panicked at aoc/src/verify.rs:"));
+        assert!(e.ends_with(": no part two for \"ab\""));
+    }
+
+    #[test]
+    fn test_answers() {
+        let dir = std::env::temp_dir().join("aoc-verify-test");
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("answers.toml");
+
+        std::fs::write(&path, "part2 = \"abc\"\npart1 = 1474\n").unwrap();
+        let expected = read_answers(&path).unwrap().unwrap();
+        assert_eq!(expected, [(1, "1474".into()), (2, "abc".into())]);
+
+        let actual = [(1, "1474".to_string()), (2, "abd".to_string())];
+        assert_eq!(
+            diff(&expected, &actual),
+            ["part 2:", "  - abc", "  + abd"]
+        );
+        assert!(diff(&expected, &expected).is_empty());
+        assert_eq!(
+            diff(&expected[..1], &actual),
+            ["part 2: unrecorded", "  + abd"]
+        );
+
+        write_answers(&path, &actual).unwrap();
+        assert_eq!(read_answers(&path).unwrap().unwrap(), actual);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(read_answers(&path), Ok(None));
+    }
+}
@@ -0,0 +1,271 @@
+/// Knuth's Algorithm X on dancing links.
+///
+/// Columns `0..primary` must be covered exactly once, the `secondary` ones
+/// after them at most once. Rows are numbered in the order they are added
+/// and solutions are reported as lists of those numbers.
+///
+/// Solutions are distinct sets of rows, so items that are interchangeable
+/// in the problem but given a column each are told apart: `c` identical
+/// copies make every arrangement show up `c!` times.
+#[derive(Debug, Clone)]
+pub struct Dlx {
+    left: Vec<usize>,
+    right: Vec<usize>,
+    up: Vec<usize>,
+    down: Vec<usize>,
+    // header of the column each node is in; headers are 1..=columns
+    column: Vec<usize>,
+    row: Vec<usize>,
+    size: Vec<usize>,
+    rows: usize,
+}
+
+const ROOT: usize = 0;
+
+impl Dlx {
+    pub fn new(primary: usize, secondary: usize) -> Self {
+        let headers = primary + secondary + 1;
+        let mut dlx = Self {
+            left: (0..headers).collect(),
+            right: (0..headers).collect(),
+            up: (0..headers).collect(),
+            down: (0..headers).collect(),
+            column: (0..headers).collect(),
+            row: vec![usize::MAX; headers],
+            size: vec![0; headers],
+            rows: 0,
+        };
+        // only primary headers are linked to the root, so the search never
+        // has to pick a secondary column
+        for c in 0..=primary {
+            dlx.right[c] = (c + 1) % (primary + 1);
+            dlx.left[(c + 1) % (primary + 1)] = c;
+        }
+        dlx
+    }
+
+    pub fn add_row(&mut self, columns: impl IntoIterator<Item = usize>) {
+        let row = self.rows;
+        self.rows += 1;
+        let mut first = None;
+        for c in columns {
+            let header = c + 1;
+            assert!(header < self.size.len(), "column {c} out of range");
+            let node = self.column.len();
+            self.column.push(header);
+            self.row.push(row);
+            self.up.push(self.up[header]);
+            self.down.push(header);
+            self.down[self.up[header]] = node;
+            self.up[header] = node;
+            self.size[header] += 1;
+            match first {
+                None => {
+                    self.left.push(node);
+                    self.right.push(node);
+                    first = Some(node);
+                }
+                Some(first) => {
+                    self.left.push(self.left[first]);
+                    self.right.push(first);
+                    self.right[self.left[first]] = node;
+                    self.left[first] = node;
+                }
+            }
+        }
+    }
+
+    fn cover(&mut self, c: usize) {
+        self.right[self.left[c]] = self.right[c];
+        self.left[self.right[c]] = self.left[c];
+        let mut i = self.down[c];
+        while i != c {
+            let mut j = self.right[i];
+            while j != i {
+                self.down[self.up[j]] = self.down[j];
+                self.up[self.down[j]] = self.up[j];
+                self.size[self.column[j]] -= 1;
+                j = self.right[j];
+            }
+            i = self.down[i];
+        }
+    }
+
+    fn uncover(&mut self, c: usize) {
+        let mut i = self.up[c];
+        while i != c {
+            let mut j = self.left[i];
+            while j != i {
+                self.size[self.column[j]] += 1;
+                self.down[self.up[j]] = j;
+                self.up[self.down[j]] = j;
+                j = self.left[j];
+            }
+            i = self.up[i];
+        }
+        self.right[self.left[c]] = c;
+        self.left[self.right[c]] = c;
+    }
+
+    fn cover_row(&mut self, node: usize) {
+        let mut j = self.right[node];
+        while j != node {
+            self.cover(self.column[j]);
+            j = self.right[j];
+        }
+    }
+
+    fn uncover_row(&mut self, node: usize) {
+        let mut j = self.left[node];
+        while j != node {
+            self.uncover(self.column[j]);
+            j = self.left[j];
+        }
+    }
+
+    /// The uncovered primary column with the fewest rows left.
+    fn choose(&self) -> Option<usize> {
+        let mut best = None;
+        let mut c = self.right[ROOT];
+        while c != ROOT {
+            if best.is_none_or(|b| self.size[c] < self.size[b]) {
+                best = Some(c);
+            }
+            c = self.right[c];
+        }
+        best
+    }
+
+    /// Lazily walks every solution. The links are restored once the
+    /// iterator is exhausted or dropped.
+    pub fn solutions(&mut self) -> Solutions<'_> {
+        Solutions {
+            dlx: self,
+            stack: vec![],
+            backtrack: false,
+            done: false,
+        }
+    }
+
+    pub fn first(&mut self) -> Option<Vec<usize>> {
+        self.solutions().next()
+    }
+
+    pub fn count(&mut self) -> usize {
+        self.solutions().count()
+    }
+}
+
+pub struct Solutions<'a> {
+    dlx: &'a mut Dlx,
+    // the node of every row chosen so far
+    stack: Vec<usize>,
+    backtrack: bool,
+    done: bool,
+}
+
+impl Solutions<'_> {
+    /// Replaces the deepest choice with the next row of its column,
+    /// dropping exhausted columns. Returns false once the search is over.
+    fn advance(&mut self) -> bool {
+        while let Some(node) = self.stack.pop() {
+            self.dlx.uncover_row(node);
+            let c = self.dlx.column[node];
+            let next = self.dlx.down[node];
+            if next == c {
+                self.dlx.uncover(c);
+                continue;
+            }
+            self.dlx.cover_row(next);
+            self.stack.push(next);
+            return true;
+        }
+        false
+    }
+}
+
+impl Iterator for Solutions<'_> {
+    type Item = Vec<usize>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        if self.backtrack && !self.advance() {
+            self.done = true;
+            return None;
+        }
+        loop {
+            let Some(c) = self.dlx.choose() else {
+                self.backtrack = true;
+                let rows = self.stack.iter().map(|&n| self.dlx.row[n]);
+                return Some(rows.collect());
+            };
+            if self.dlx.size[c] == 0 {
+                if !self.advance() {
+                    self.done = true;
+                    return None;
+                }
+                continue;
+            }
+            self.dlx.cover(c);
+            let node = self.dlx.down[c];
+            self.dlx.cover_row(node);
+            self.stack.push(node);
+        }
+    }
+}
+
+impl Drop for Solutions<'_> {
+    fn drop(&mut self) {
+        while let Some(node) = self.stack.pop() {
+            self.dlx.uncover_row(node);
+            self.dlx.uncover(self.dlx.column[node]);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_knuth_example() {
+        let mut dlx = Dlx::new(7, 0);
+        for row in [
+            vec![0, 3, 6],
+            vec![0, 3],
+            vec![3, 4, 6],
+            vec![2, 4, 5],
+            vec![1, 2, 5, 6],
+            vec![1, 6],
+        ] {
+            dlx.add_row(row);
+        }
+        let mut first = dlx.first().unwrap();
+        first.sort();
+        assert_eq!(first, [1, 3, 5]);
+        assert_eq!(dlx.count(), 1);
+    }
+
+    #[test]
+    fn test_secondary_columns() {
+        let mut dlx = Dlx::new(2, 1);
+        for row in [vec![0, 2], vec![1, 2], vec![0], vec![1]] {
+            dlx.add_row(row);
+        }
+        let mut all = dlx
+            .solutions()
+            .map(|mut rows| {
+                rows.sort();
+                rows
+            })
+            .collect::<Vec<_>>();
+        all.sort();
+        assert_eq!(all, [vec![0, 3], vec![1, 2], vec![2, 3]]);
+
+        // a half-consumed iterator leaves the links intact
+        assert!(dlx.solutions().next().is_some());
+        assert_eq!(dlx.count(), 3);
+    }
+}
@@ -0,0 +1,147 @@
+use crate::graph::Graph;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+const PALETTE: [&str; 8] = [
+    "lightblue",
+    "palegreen",
+    "lightsalmon",
+    "khaki",
+    "plum",
+    "lightcyan",
+    "peachpuff",
+    "thistle",
+];
+
+/// Graphviz source for a `Graph`, ready for `dot -Tsvg`. Undirected graphs
+/// are written with each edge once.
+pub struct Dot<'a> {
+    graph: &'a Graph,
+    highlighted: Vec<usize>,
+    fill: HashMap<usize, &'static str>,
+    weights: bool,
+}
+
+impl<'a> Dot<'a> {
+    pub fn new(graph: &'a Graph) -> Self {
+        Self {
+            graph,
+            highlighted: vec![],
+            fill: HashMap::new(),
+            weights: false,
+        }
+    }
+
+    /// Draws the given nodes with a thick red outline.
+    pub fn highlight(mut self, ids: impl IntoIterator<Item = usize>) -> Self {
+        self.highlighted.extend(ids);
+        self
+    }
+
+    /// Fills each group of nodes with its own colour, cycling through a
+    /// small palette. Singleton groups are left blank to keep large
+    /// pictures readable.
+    pub fn components(mut self, groups: &[Vec<usize>]) -> Self {
+        let groups = groups.iter().filter(|group| group.len() > 1);
+        for (group, colour) in groups.zip(PALETTE.iter().cycle()) {
+            for &id in group {
+                self.fill.insert(id, colour);
+            }
+        }
+        self
+    }
+
+    /// Labels every edge with its weight.
+    pub fn weights(mut self) -> Self {
+        self.weights = true;
+        self
+    }
+}
+
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+impl Display for Dot<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (keyword, arrow) = if self.graph.is_directed() {
+            ("digraph", "->")
+        } else {
+            ("graph", "--")
+        };
+        writeln!(f, "{keyword} {{")?;
+        for id in 0..self.graph.len() {
+            let mut attrs = vec![];
+            if let Some(colour) = self.fill.get(&id) {
+                attrs.push(format!("style=filled, fillcolor={colour}"));
+            }
+            if self.highlighted.contains(&id) {
+                attrs.push("color=red, penwidth=3".to_string());
+            }
+            write!(f, "    {}", quote(self.graph.name(id)))?;
+            if !attrs.is_empty() {
+                write!(f, " [{}]", attrs.join(", "))?;
+            }
+            writeln!(f, ";")?;
+        }
+        for from in 0..self.graph.len() {
+            for edge in self.graph.edges(from) {
+                if !self.graph.is_directed() && edge.to < from {
+                    continue;
+                }
+                write!(
+                    f,
+                    "    {} {arrow} {}",
+                    quote(self.graph.name(from)),
+                    quote(self.graph.name(edge.to))
+                )?;
+                if self.weights {
+                    write!(f, " [label={}]", edge.weight)?;
+                }
+                writeln!(f, ";")?;
+            }
+        }
+        writeln!(f, "}}")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_dot() {
+        let graph = Graph::parse("svr: fft\nfft: out", true).unwrap();
+        let dot = Dot::new(&graph).highlight([1]);
+        assert_eq!(
+            dot.to_string(),
+            indoc! {r#"
+                digraph {
+                    "svr";
+                    "fft" [color=red, penwidth=3];
+                    "out";
+                    "svr" -> "fft";
+                    "fft" -> "out";
+                }
+            "#}
+        );
+
+        let mut graph = Graph::undirected();
+        let (a, b) = (graph.intern("a\"1"), graph.intern("b"));
+        graph.intern("c");
+        graph.add_edge(a, b, 7);
+        let dot = Dot::new(&graph).components(&graph.sccs()).weights();
+        assert_eq!(
+            dot.to_string(),
+            indoc! {r#"
+                graph {
+                    "a\"1" [style=filled, fillcolor=lightblue];
+                    "b" [style=filled, fillcolor=lightblue];
+                    "c";
+                    "a\"1" -- "b" [label=7];
+                }
+            "#}
+        );
+    }
+}
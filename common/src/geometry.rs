@@ -0,0 +1,347 @@
+use itertools::Itertools;
+
+pub type Point = (isize, isize);
+
+/// Inclusive tile area of the axis-aligned rectangle spanned by two
+/// opposite corners, so `(2, 3)` and `(2, 3)` is a single tile.
+pub fn get_size(a: Point, b: Point) -> isize {
+    ((a.0.abs_diff(b.0) + 1) * (a.1.abs_diff(b.1) + 1)) as isize
+}
+
+/// Splits the sorted distinct coordinates into inclusive spans: one span per
+/// coordinate plus one for every non-empty gap between neighbours. Every
+/// tile inside a span shares the same inside/outside classification.
+fn compress(mut values: Vec<isize>) -> Vec<(isize, isize)> {
+    values.sort_unstable();
+    values.dedup();
+    let mut spans = Vec::with_capacity(values.len() * 2);
+    for (i, &v) in values.iter().enumerate() {
+        if i > 0 && values[i - 1] + 1 < v {
+            spans.push((values[i - 1] + 1, v - 1));
+        }
+        spans.push((v, v));
+    }
+    spans
+}
+
+fn locate(spans: &[(isize, isize)], v: isize) -> Option<usize> {
+    let idx = spans.partition_point(|&(_, hi)| hi < v);
+    spans.get(idx).filter(|&&(lo, _)| lo <= v).map(|_| idx)
+}
+
+/// A rectilinear polygon rasterised on its coordinate-compressed grid.
+///
+/// The boundary runs through the tile centres of consecutive corners, and
+/// both boundary and interior tiles count as inside. Building costs
+/// O(n² log n) for n corners; afterwards `contains_rect` answers in O(1)
+/// from a 2D prefix sum, plus the binary search that locates its corners.
+#[derive(Debug, Clone)]
+pub struct CompressedPolygon {
+    xs: Vec<(isize, isize)>,
+    ys: Vec<(isize, isize)>,
+    inside: Vec<Vec<bool>>,
+    prefix: Vec<Vec<usize>>,
+}
+
+/// An axis-aligned rectangle of tiles, both corners inclusive.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point,
+    pub max: Point,
+}
+
+impl Rect {
+    pub fn new(a: Point, b: Point) -> Self {
+        Self {
+            min: (a.0.min(b.0), a.1.min(b.1)),
+            max: (a.0.max(b.0), a.1.max(b.1)),
+        }
+    }
+
+    pub fn area(&self) -> isize {
+        get_size(self.min, self.max)
+    }
+}
+
+impl CompressedPolygon {
+    pub fn new(corners: &[Point]) -> Self {
+        Self::with_obstacles(corners, &[])
+    }
+
+    /// Like `new`, but every obstacle tile counts as outside the polygon, so
+    /// rectangle queries have to avoid them.
+    pub fn with_obstacles(corners: &[Point], obstacles: &[Point]) -> Self {
+        let points = || corners.iter().chain(obstacles);
+        let xs = compress(points().map(|p| p.0).collect());
+        let ys = compress(points().map(|p| p.1).collect());
+
+        let mut inside = vec![vec![false; ys.len()]; xs.len()];
+        let n = corners.len();
+        for i in 0..n {
+            let (a, b) = (corners[i], corners[(i + 1) % n]);
+            let (ax, ay) =
+                (locate(&xs, a.0).unwrap(), locate(&ys, a.1).unwrap());
+            let (bx, by) =
+                (locate(&xs, b.0).unwrap(), locate(&ys, b.1).unwrap());
+            for column in &mut inside[ax.min(bx)..=ax.max(bx)] {
+                column[ay.min(by)..=ay.max(by)].fill(true);
+            }
+        }
+
+        // Every other cell lies strictly off the boundary, so one tile
+        // decides it: cast a ray towards -x from the cell's first tile and
+        // count the vertical edges it crosses. The half-open span keeps a
+        // ray through a corner from counting both of its edges. Flooding
+        // the cell grid instead would seal pockets that only open to the
+        // outside through a gap between edges one tile apart.
+        for (y, &(py, _)) in ys.iter().enumerate() {
+            let crossings = (0..n)
+                .map(|i| (corners[i], corners[(i + 1) % n]))
+                .filter(|(a, b)| a.0 == b.0)
+                .filter(|(a, b)| a.1.min(b.1) <= py && py < a.1.max(b.1))
+                .map(|(a, _)| a.0)
+                .sorted_unstable()
+                .collect_vec();
+            for (x, &(px, _)) in xs.iter().enumerate() {
+                if !inside[x][y] {
+                    let left = crossings.partition_point(|&cx| cx < px);
+                    inside[x][y] = left % 2 == 1;
+                }
+            }
+        }
+
+        for &(ox, oy) in obstacles {
+            inside[locate(&xs, ox).unwrap()][locate(&ys, oy).unwrap()] = false;
+        }
+
+        let mut prefix = vec![vec![0; ys.len() + 1]; xs.len() + 1];
+        for x in 0..xs.len() {
+            for y in 0..ys.len() {
+                let inside = usize::from(inside[x][y]);
+                prefix[x + 1][y + 1] =
+                    inside + prefix[x][y + 1] + prefix[x + 1][y]
+                        - prefix[x][y];
+            }
+        }
+
+        Self {
+            xs,
+            ys,
+            inside,
+            prefix,
+        }
+    }
+
+    /// Whether every tile of the rectangle spanned by `a` and `b` lies on
+    /// the boundary or in the interior of the polygon.
+    pub fn contains_rect(&self, a: Point, b: Point) -> bool {
+        let (Some(x1), Some(x2), Some(y1), Some(y2)) = (
+            locate(&self.xs, a.0.min(b.0)),
+            locate(&self.xs, a.0.max(b.0)),
+            locate(&self.ys, a.1.min(b.1)),
+            locate(&self.ys, a.1.max(b.1)),
+        ) else {
+            return false;
+        };
+        let inside = self.prefix[x2 + 1][y2 + 1] + self.prefix[x1][y1]
+            - self.prefix[x1][y2 + 1]
+            - self.prefix[x2 + 1][y1];
+        inside == (x2 - x1 + 1) * (y2 - y1 + 1)
+    }
+
+    pub fn contains(&self, p: Point) -> bool {
+        self.contains_rect(p, p)
+    }
+
+    /// The `k` largest inside rectangles whose opposite corners are both
+    /// taken from `corners`, largest first.
+    pub fn top_corner_rectangles(
+        &self,
+        corners: &[Point],
+        k: usize,
+    ) -> Vec<Rect> {
+        corners
+            .iter()
+            .tuple_combinations()
+            .filter(|&(&a, &b)| self.contains_rect(a, b))
+            .map(|(&a, &b)| Rect::new(a, b))
+            .sorted_by(|r1, r2| {
+                r2.area()
+                    .cmp(&r1.area())
+                    .then_with(|| (r1.min, r1.max).cmp(&(r2.min, r2.max)))
+            })
+            .dedup()
+            .take(k)
+            .collect()
+    }
+
+    pub fn largest_corner_rectangle(&self, corners: &[Point]) -> Option<Rect> {
+        self.top_corner_rectangles(corners, 1).pop()
+    }
+
+    /// The largest inside rectangle with arbitrary corners.
+    ///
+    /// An optimal rectangle always has its sides on span boundaries, so
+    /// this is the largest rectangle under a histogram swept over the
+    /// compressed rows, with every bar and column weighted by the number of
+    /// tiles its span covers: O(cells) overall.
+    pub fn largest_inscribed_rectangle(&self) -> Option<Rect> {
+        let span_len = |(lo, hi): (isize, isize)| hi - lo + 1;
+        let mut heights = vec![0; self.xs.len()];
+        let mut best: Option<Rect> = None;
+
+        for (y, &y_span) in self.ys.iter().enumerate() {
+            for (x, height) in heights.iter_mut().enumerate() {
+                *height = if self.inside[x][y] {
+                    *height + span_len(y_span)
+                } else {
+                    0
+                };
+            }
+
+            // bars of increasing height with the column they extend back to
+            let mut stack: Vec<(usize, isize)> = vec![];
+            for x in 0..=self.xs.len() {
+                let height = heights.get(x).copied().unwrap_or(0);
+                let mut start = x;
+                while let Some(&(left, bar)) = stack.last() {
+                    if bar < height {
+                        break;
+                    }
+                    stack.pop();
+                    let rect = Rect::new(
+                        (self.xs[left].0, y_span.1 - bar + 1),
+                        (self.xs[x - 1].1, y_span.1),
+                    );
+                    if best.is_none_or(|b| rect.area() > b.area()) {
+                        best = Some(rect);
+                    }
+                    start = left;
+                }
+                if height > 0 {
+                    stack.push((start, height));
+                }
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: [Point; 8] = [
+        (7, 1),
+        (11, 1),
+        (11, 7),
+        (9, 7),
+        (9, 5),
+        (2, 5),
+        (2, 3),
+        (7, 3),
+    ];
+
+    #[test]
+    fn test_contains_rect() {
+        let polygon = CompressedPolygon::new(&EXAMPLE);
+        assert!(polygon.contains_rect((9, 5), (2, 3)));
+        assert!(!polygon.contains_rect((7, 1), (11, 7)));
+        assert!(polygon.contains_rect((9, 7), (11, 1)));
+        assert!(polygon.contains((4, 4)));
+        assert!(!polygon.contains((4, 2)));
+        assert!(!polygon.contains((100, 100)));
+        assert_eq!(get_size((9, 5), (2, 3)), 24);
+    }
+
+    #[test]
+    fn test_concave_notch() {
+        // a U shape whose notch is a single column wide: the rectangle
+        // across the top bar is inside, the one across the notch is not
+        let polygon = CompressedPolygon::new(&[
+            (0, 0),
+            (4, 0),
+            (4, 4),
+            (3, 4),
+            (3, 1),
+            (1, 1),
+            (1, 4),
+            (0, 4),
+        ]);
+        assert!(polygon.contains_rect((0, 0), (4, 1)));
+        assert!(!polygon.contains_rect((0, 0), (4, 2)));
+        assert!(polygon.contains_rect((3, 1), (4, 4)));
+        assert!(!polygon.contains((2, 3)));
+        assert_eq!(
+            polygon.largest_inscribed_rectangle(),
+            Some(Rect::new((0, 0), (4, 1)))
+        );
+    }
+
+    #[test]
+    fn test_pocket_through_gap() {
+        // the square pocket in the middle reaches the outside only between
+        // the edges at x = 2 and x = 3, which leave no tile between them
+        let polygon = CompressedPolygon::new(&[
+            (0, 0),
+            (10, 0),
+            (10, 10),
+            (3, 10),
+            (3, 8),
+            (8, 8),
+            (8, 2),
+            (2, 2),
+            (2, 10),
+            (0, 10),
+        ]);
+        assert!(!polygon.contains((5, 5)));
+        assert!(!polygon.contains_rect((3, 3), (7, 7)));
+        assert!(polygon.contains_rect((2, 2), (8, 2)));
+        assert!(polygon.contains_rect((2, 8), (3, 10)));
+    }
+
+    #[test]
+    fn test_search_modes() {
+        let polygon = CompressedPolygon::new(&EXAMPLE);
+        let top = polygon.top_corner_rectangles(&EXAMPLE, 3);
+        assert_eq!(top[0], Rect::new((2, 3), (9, 5)));
+        assert_eq!(top.iter().map(Rect::area).collect_vec(), [24, 21, 18]);
+        assert_eq!(
+            polygon.largest_inscribed_rectangle(),
+            Some(Rect::new((2, 3), (11, 5)))
+        );
+
+        let polygon = CompressedPolygon::with_obstacles(&EXAMPLE, &[(5, 4)]);
+        assert_eq!(
+            polygon.largest_inscribed_rectangle(),
+            Some(Rect::new((7, 1), (11, 5)))
+        );
+        assert_eq!(
+            polygon.largest_corner_rectangle(&EXAMPLE),
+            Some(Rect::new((9, 1), (11, 7)))
+        );
+
+        // the pocket behind the zero-width gap must not be covered
+        let corners = [
+            (0, 0),
+            (10, 0),
+            (10, 10),
+            (3, 10),
+            (3, 8),
+            (8, 8),
+            (8, 2),
+            (2, 2),
+            (2, 10),
+            (0, 10),
+        ];
+        let polygon = CompressedPolygon::new(&corners);
+        assert_eq!(
+            polygon.largest_inscribed_rectangle(),
+            Some(Rect::new((0, 0), (10, 2)))
+        );
+        assert_eq!(
+            polygon.largest_corner_rectangle(&corners),
+            Some(Rect::new((0, 0), (2, 10)))
+        );
+    }
+}
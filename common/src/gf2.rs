@@ -0,0 +1,238 @@
+use std::ops::BitXorAssign;
+
+/// A fixed-length row of bits over GF(2), packed into `u64` words so it is
+/// not limited to the width of a machine integer.
+#[derive(Clone, Eq, PartialEq, Hash, Debug, Default)]
+pub struct BitRow {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl BitRow {
+    pub fn new(len: usize) -> Self {
+        Self {
+            words: vec![0; len.div_ceil(64)],
+            len,
+        }
+    }
+
+    pub fn from_bits(bits: impl IntoIterator<Item = bool>) -> Self {
+        let bits = bits.into_iter().collect::<Vec<_>>();
+        let mut row = Self::new(bits.len());
+        for (i, bit) in bits.into_iter().enumerate() {
+            row.set(i, bit);
+        }
+        row
+    }
+
+    pub fn from_ones(
+        len: usize,
+        ones: impl IntoIterator<Item = usize>,
+    ) -> Self {
+        let mut row = Self::new(len);
+        for i in ones {
+            row.set(i, true);
+        }
+        row
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, i: usize) -> bool {
+        assert!(i < self.len, "bit {i} out of range for {} bits", self.len);
+        self.words[i / 64] >> (i % 64) & 1 == 1
+    }
+
+    pub fn set(&mut self, i: usize, bit: bool) {
+        assert!(i < self.len, "bit {i} out of range for {} bits", self.len);
+        if bit {
+            self.words[i / 64] |= 1 << (i % 64);
+        } else {
+            self.words[i / 64] &= !(1 << (i % 64));
+        }
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.words.iter().all(|&w| w == 0)
+    }
+
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    pub fn iter_bits(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len).map(|i| self.get(i))
+    }
+
+    pub fn iter_ones(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.len).filter(|&i| self.get(i))
+    }
+}
+
+impl BitXorAssign<&BitRow> for BitRow {
+    fn bitxor_assign(&mut self, rhs: &BitRow) {
+        assert_eq!(self.len, rhs.len, "xor of rows with different widths");
+        for (a, b) in self.words.iter_mut().zip(&rhs.words) {
+            *a ^= b;
+        }
+    }
+}
+
+/// A system of linear equations over GF(2), one `BitRow` per equation.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct BitMatrix {
+    pub rows: Vec<BitRow>,
+    pub cols: usize,
+}
+
+impl BitMatrix {
+    pub fn new(cols: usize) -> Self {
+        Self { rows: vec![], cols }
+    }
+
+    pub fn push_row(&mut self, row: BitRow) {
+        assert_eq!(row.len(), self.cols, "row width must match the matrix");
+        self.rows.push(row);
+    }
+}
+
+/// Every solution of `A x = b`: the particular solution xor any combination
+/// of the nullspace basis vectors.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct SolutionSpace {
+    pub particular: BitRow,
+    pub nullspace: Vec<BitRow>,
+}
+
+impl SolutionSpace {
+    /// Walks all 2^k solutions in Gray-code order, so each step is a single
+    /// xor with one basis vector.
+    pub fn solutions(&self) -> impl Iterator<Item = BitRow> + '_ {
+        let k = self.nullspace.len();
+        assert!(
+            k < 64,
+            "nullspace of dimension {k} is too large to enumerate"
+        );
+        let mut current = self.particular.clone();
+        (0u64..1 << k).map(move |step| {
+            if step > 0 {
+                current ^= &self.nullspace[step.trailing_zeros() as usize];
+            }
+            current.clone()
+        })
+    }
+
+    /// The solution with the fewest ones, found by enumerating the
+    /// nullspace: exponential in its dimension only, which stays small for
+    /// puzzle inputs.
+    pub fn min_weight(&self) -> BitRow {
+        self.solutions().min_by_key(BitRow::count_ones).unwrap()
+    }
+}
+
+/// Solves `A x = b` by Gauss-Jordan elimination, returning `None` when the
+/// system is inconsistent.
+pub fn solve(a: &BitMatrix, b: &BitRow) -> Option<SolutionSpace> {
+    assert_eq!(a.rows.len(), b.len(), "one target bit per equation");
+    let n = a.cols;
+    // augment each equation with its target bit in the last column
+    let mut rows = a
+        .rows
+        .iter()
+        .zip(b.iter_bits())
+        .map(|(row, bit)| {
+            let mut aug = BitRow::from_ones(n + 1, row.iter_ones());
+            aug.set(n, bit);
+            aug
+        })
+        .collect::<Vec<_>>();
+
+    let mut pivots = vec![];
+    for col in 0..n {
+        let r = pivots.len();
+        let Some(p) = (r..rows.len()).find(|&i| rows[i].get(col)) else {
+            continue;
+        };
+        rows.swap(r, p);
+        let pivot = rows[r].clone();
+        for (i, row) in rows.iter_mut().enumerate() {
+            if i != r && row.get(col) {
+                *row ^= &pivot;
+            }
+        }
+        pivots.push(col);
+    }
+
+    if rows[pivots.len()..].iter().any(|row| row.get(n)) {
+        return None;
+    }
+
+    let mut particular = BitRow::new(n);
+    for (r, &col) in pivots.iter().enumerate() {
+        particular.set(col, rows[r].get(n));
+    }
+    let nullspace = (0..n)
+        .filter(|col| !pivots.contains(col))
+        .map(|free| {
+            let mut v = BitRow::new(n);
+            v.set(free, true);
+            for (r, &col) in pivots.iter().enumerate() {
+                v.set(col, rows[r].get(free));
+            }
+            v
+        })
+        .collect();
+
+    Some(SolutionSpace {
+        particular,
+        nullspace,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_min_weight() {
+        // [.##.] (3) (1,3) (2) (2,3) (0,2) (0,1)
+        let buttons = [
+            vec![3],
+            vec![1, 3],
+            vec![2],
+            vec![2, 3],
+            vec![0, 2],
+            vec![0, 1],
+        ];
+        let mut a = BitMatrix::new(buttons.len());
+        for light in 0..4 {
+            let ones =
+                (0..buttons.len()).filter(|&b| buttons[b].contains(&light));
+            a.push_row(BitRow::from_ones(buttons.len(), ones));
+        }
+        let target = BitRow::from_bits([false, true, true, false]);
+        let space = solve(&a, &target).unwrap();
+        assert_eq!(space.nullspace.len(), 2);
+        assert_eq!(space.solutions().count(), 4);
+        assert_eq!(space.min_weight().count_ones(), 2);
+    }
+
+    #[test]
+    fn test_wide_and_inconsistent() {
+        let mut a = BitMatrix::new(100);
+        a.push_row(BitRow::from_ones(100, [0, 99]));
+        a.push_row(BitRow::from_ones(100, [99]));
+        let space = solve(&a, &BitRow::from_bits([true, true])).unwrap();
+        assert_eq!(space.particular.iter_ones().collect::<Vec<_>>(), [99]);
+        assert_eq!(space.nullspace.len(), 98);
+
+        a.push_row(BitRow::from_ones(100, [0]));
+        assert_eq!(solve(&a, &BitRow::from_bits([true, true, true])), None);
+    }
+}
@@ -0,0 +1,542 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// A directed cycle as the chain of node ids walked around it, starting and
+/// ending at the same node.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cycle(pub Vec<usize>);
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let chain = self.0.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+        write!(f, "cycle {}", chain.join(" -> "))
+    }
+}
+
+impl Error for Cycle {}
+
+/// A line of an adjacency list that names no edge, at its 1-based number.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoEdge {
+    pub line: usize,
+    pub text: String,
+}
+
+impl Display for NoEdge {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "line {}: no edge in {:?}", self.line, self.text)
+    }
+}
+
+impl Error for NoEdge {}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Visit {
+    New,
+    OnStack,
+    Done,
+}
+
+/// Nodes reachable from `roots` without entering a blocked node, in
+/// topological order, or the first cycle found among them.
+fn topo_order(
+    adj: &[Vec<usize>],
+    roots: impl IntoIterator<Item = usize>,
+    blocked: &[bool],
+) -> Result<Vec<usize>, Cycle> {
+    fn dfs(
+        u: usize,
+        adj: &[Vec<usize>],
+        blocked: &[bool],
+        visits: &mut Vec<Visit>,
+        stack: &mut Vec<usize>,
+        order: &mut Vec<usize>,
+    ) -> Result<(), Cycle> {
+        match visits[u] {
+            Visit::Done => return Ok(()),
+            Visit::OnStack => {
+                let start = stack.iter().position(|&v| v == u).unwrap();
+                let mut chain = stack[start..].to_vec();
+                chain.push(u);
+                return Err(Cycle(chain));
+            }
+            Visit::New => {}
+        }
+        visits[u] = Visit::OnStack;
+        stack.push(u);
+        for &v in adj[u].iter().filter(|&&v| !blocked[v]) {
+            dfs(v, adj, blocked, visits, stack, order)?;
+        }
+        stack.pop();
+        visits[u] = Visit::Done;
+        order.push(u);
+        Ok(())
+    }
+
+    let mut order = vec![];
+    let mut visits = vec![Visit::New; adj.len()];
+    for root in roots.into_iter().filter(|&r| !blocked[r]) {
+        dfs(root, adj, blocked, &mut visits, &mut vec![], &mut order)?;
+    }
+    order.reverse();
+    Ok(order)
+}
+
+/// Nodes from which `to` can be reached without entering a blocked node,
+/// `to` itself included unless it is blocked.
+fn reaching(adj: &[Vec<usize>], to: usize, blocked: &[bool]) -> Vec<bool> {
+    let mut reverse = vec![vec![]; adj.len()];
+    for (u, next) in adj.iter().enumerate() {
+        for &v in next {
+            reverse[v].push(u);
+        }
+    }
+    let mut seen = vec![false; adj.len()];
+    let mut stack = vec![];
+    if !blocked[to] {
+        seen[to] = true;
+        stack.push(to);
+    }
+    while let Some(v) = stack.pop() {
+        for &u in &reverse[v] {
+            if !seen[u] && !blocked[u] {
+                seen[u] = true;
+                stack.push(u);
+            }
+        }
+    }
+    seen
+}
+
+/// Constraints on the paths counted by `count_paths_through`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PathQuery {
+    /// Nodes every path has to visit.
+    pub waypoints: Vec<usize>,
+    /// Whether the waypoints have to be visited in the order given.
+    pub ordered: bool,
+    /// Nodes no path may enter.
+    pub avoid: Vec<usize>,
+}
+
+/// Counts the paths from `from` to `to` that satisfy `query`.
+///
+/// Walks the nodes that lie on some path from `from` to `to` in
+/// topological order, carrying for every node how many paths arrive there
+/// per set of waypoints seen so far (or, when ordered, per number of
+/// waypoints seen). Only states that actually occur are stored, which keeps
+/// ~16 waypoints cheap. Paths end at their first arrival at `to`. A cycle
+/// on the way from `from` to `to` would allow infinitely many walks, so it
+/// is reported instead; cycles past `to` or in branches that never get
+/// there don't matter.
+///
+/// Panics if a waypoint is listed twice.
+pub fn count_paths_through(
+    adj: &[Vec<usize>],
+    from: usize,
+    to: usize,
+    query: &PathQuery,
+) -> Result<u128, Cycle> {
+    let k = query.waypoints.len();
+    assert!(k < 32, "at most 31 waypoints are supported, got {k}");
+    let mut waypoint = vec![None; adj.len()];
+    for (i, &w) in query.waypoints.iter().enumerate() {
+        assert!(waypoint[w].is_none(), "waypoint {w} is listed twice");
+        waypoint[w] = Some(i);
+    }
+    let mut blocked = vec![false; adj.len()];
+    for &v in &query.avoid {
+        blocked[v] = true;
+    }
+    // nothing that cannot get to `to` is counted, nor is anything after it
+    let reaches = reaching(adj, to, &blocked);
+    for (v, blocked) in blocked.iter_mut().enumerate() {
+        *blocked |= !reaches[v];
+    }
+    let mut adj = adj.to_vec();
+    adj[to].clear();
+
+    // the state after arriving at `v`, or None if the path is ruled out
+    let step = |state: u32, v: usize| match waypoint[v] {
+        None => Some(state),
+        Some(i) if query.ordered => (state == i as u32).then_some(state + 1),
+        Some(i) => Some(state | 1 << i),
+    };
+    let full = if query.ordered {
+        k as u32
+    } else {
+        (1 << k) - 1
+    };
+
+    let order = topo_order(&adj, [from], &blocked)?;
+    let mut counts: Vec<HashMap<u32, u128>> = vec![HashMap::new(); adj.len()];
+    if let Some(state) = step(0, from) {
+        counts[from].insert(state, 1);
+    }
+    for u in order {
+        if u == to {
+            continue;
+        }
+        let arrived = std::mem::take(&mut counts[u]);
+        for v in adj[u].iter().copied().filter(|&v| !blocked[v]) {
+            for (&state, &count) in &arrived {
+                if let Some(next) = step(state, v) {
+                    *counts[v].entry(next).or_default() += count;
+                }
+            }
+        }
+    }
+    Ok(counts[to].get(&full).copied().unwrap_or(0))
+}
+
+/// Counts all paths from `from` to `to`, see `count_paths_through`.
+pub fn count_paths(
+    adj: &[Vec<usize>],
+    from: usize,
+    to: usize,
+) -> Result<u128, Cycle> {
+    count_paths_through(adj, from, to, &PathQuery::default())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Edge {
+    pub to: usize,
+    pub weight: i64,
+}
+
+/// A graph over named nodes. Names are interned to dense ids in the order
+/// they are first seen, so algorithms work on plain indices and only the
+/// edges of an undirected graph are stored in both directions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    names: Vec<String>,
+    ids: HashMap<String, usize>,
+    adj: Vec<Vec<Edge>>,
+    directed: bool,
+}
+
+impl Graph {
+    pub fn directed() -> Self {
+        Self {
+            directed: true,
+            ..Self::default()
+        }
+    }
+
+    pub fn undirected() -> Self {
+        Self::default()
+    }
+
+    /// Parses one adjacency per line, each in any of the formats `a: b c`,
+    /// `a-b` or `a -> b, c`. The format is unweighted, so every edge gets
+    /// weight 1; use `add_edge` for anything else. Blank lines are skipped,
+    /// but a line missing its source or all of its targets is an error.
+    pub fn parse(input: &str, directed: bool) -> Result<Self, NoEdge> {
+        let mut graph = if directed {
+            Self::directed()
+        } else {
+            Self::undirected()
+        };
+        for (i, line) in input.lines().map(str::trim).enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let no_edge = || NoEdge {
+                line: i + 1,
+                text: line.to_string(),
+            };
+            let (from, targets) = line
+                .split_once("->")
+                .or_else(|| line.split_once(':'))
+                .or_else(|| line.split_once('-'))
+                .ok_or_else(no_edge)?;
+            let (from, targets) = (
+                from.trim(),
+                targets
+                    .split(|c: char| c == ',' || c.is_whitespace())
+                    .filter(|t| !t.is_empty())
+                    .collect::<Vec<_>>(),
+            );
+            if from.is_empty() || targets.is_empty() {
+                return Err(no_edge());
+            }
+            let from = graph.intern(from);
+            for to in targets {
+                let to = graph.intern(to);
+                graph.add_edge(from, to, 1);
+            }
+        }
+        Ok(graph)
+    }
+
+    pub fn is_directed(&self) -> bool {
+        self.directed
+    }
+
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// The id of `name`, adding it as a node without edges if it is new.
+    pub fn intern(&mut self, name: &str) -> usize {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.ids.insert(name.to_string(), id);
+        self.names.push(name.to_string());
+        self.adj.push(Vec::new());
+        id
+    }
+
+    pub fn id(&self, name: &str) -> Option<usize> {
+        self.ids.get(name).copied()
+    }
+
+    pub fn name(&self, id: usize) -> &str {
+        &self.names[id]
+    }
+
+    pub fn add_edge(&mut self, from: usize, to: usize, weight: i64) {
+        self.adj[from].push(Edge { to, weight });
+        if !self.directed && from != to {
+            self.adj[to].push(Edge { to: from, weight });
+        }
+    }
+
+    pub fn edges(&self, id: usize) -> &[Edge] {
+        &self.adj[id]
+    }
+
+    pub fn neighbours(&self, id: usize) -> impl Iterator<Item = usize> + '_ {
+        self.adj[id].iter().map(|e| e.to)
+    }
+
+    /// Plain adjacency lists, as taken by the free path-counting functions.
+    pub fn adjacency(&self) -> Vec<Vec<usize>> {
+        (0..self.len())
+            .map(|id| self.neighbours(id).collect())
+            .collect()
+    }
+
+    /// The same nodes, ids included, with every edge pointing the other way.
+    pub fn reversed(&self) -> Self {
+        let mut reversed = Self {
+            adj: vec![Vec::new(); self.len()],
+            ..self.clone()
+        };
+        for (from, edges) in self.adj.iter().enumerate() {
+            for e in edges {
+                reversed.adj[e.to].push(Edge {
+                    to: from,
+                    weight: e.weight,
+                });
+            }
+        }
+        reversed
+    }
+
+    pub fn reachable(&self, from: usize) -> Vec<bool> {
+        let mut seen = vec![false; self.len()];
+        let mut stack = vec![from];
+        seen[from] = true;
+        while let Some(u) = stack.pop() {
+            for v in self.neighbours(u) {
+                if !seen[v] {
+                    seen[v] = true;
+                    stack.push(v);
+                }
+            }
+        }
+        seen
+    }
+
+    /// All nodes ordered so that every edge points forwards. Only
+    /// meaningful for directed graphs: any undirected edge is a cycle.
+    pub fn topological_sort(&self) -> Result<Vec<usize>, Cycle> {
+        topo_order(&self.adjacency(), 0..self.len(), &vec![false; self.len()])
+    }
+
+    /// Strongly connected components with Tarjan's algorithm, each listed
+    /// after every component it has edges into.
+    pub fn sccs(&self) -> Vec<Vec<usize>> {
+        struct Tarjan<'a> {
+            graph: &'a Graph,
+            index: Vec<Option<usize>>,
+            low: Vec<usize>,
+            on_stack: Vec<bool>,
+            stack: Vec<usize>,
+            next: usize,
+            components: Vec<Vec<usize>>,
+        }
+
+        impl Tarjan<'_> {
+            fn visit(&mut self, u: usize) {
+                self.index[u] = Some(self.next);
+                self.low[u] = self.next;
+                self.next += 1;
+                self.stack.push(u);
+                self.on_stack[u] = true;
+
+                for v in self.graph.neighbours(u) {
+                    match self.index[v] {
+                        None => {
+                            self.visit(v);
+                            self.low[u] = self.low[u].min(self.low[v]);
+                        }
+                        Some(i) if self.on_stack[v] => {
+                            self.low[u] = self.low[u].min(i);
+                        }
+                        Some(_) => {}
+                    }
+                }
+
+                if Some(self.low[u]) == self.index[u] {
+                    let mut component = vec![];
+                    while let Some(v) = self.stack.pop() {
+                        self.on_stack[v] = false;
+                        component.push(v);
+                        if v == u {
+                            break;
+                        }
+                    }
+                    self.components.push(component);
+                }
+            }
+        }
+
+        let n = self.len();
+        let mut tarjan = Tarjan {
+            graph: self,
+            index: vec![None; n],
+            low: vec![0; n],
+            on_stack: vec![false; n],
+            stack: vec![],
+            next: 0,
+            components: vec![],
+        };
+        for u in 0..n {
+            if tarjan.index[u].is_none() {
+                tarjan.visit(u);
+            }
+        }
+        tarjan.components
+    }
+
+    pub fn count_paths(
+        &self,
+        from: usize,
+        to: usize,
+        query: &PathQuery,
+    ) -> Result<u128, Cycle> {
+        count_paths_through(&self.adjacency(), from, to, query)
+    }
+
+    /// Renders a cycle found in this graph with node names.
+    pub fn describe(&self, cycle: &Cycle) -> String {
+        let chain =
+            cycle.0.iter().map(|&id| self.name(id)).collect::<Vec<_>>();
+        format!("cycle {}", chain.join(" -> "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_paths() {
+        // 0 fans out to 1 and 2, which both reach 3; 4 is a dead end
+        let adj = vec![vec![1, 2, 4], vec![3], vec![1, 3], vec![], vec![]];
+        assert_eq!(count_paths(&adj, 0, 3), Ok(3));
+        assert_eq!(count_paths(&adj, 3, 0), Ok(0));
+
+        let adj = vec![vec![1], vec![2], vec![1, 3], vec![]];
+        assert_eq!(count_paths(&adj, 0, 3), Err(Cycle(vec![1, 2, 1])));
+
+        // a cycle after `to`, and one in a branch that never reaches it
+        let adj = vec![vec![1], vec![2], vec![3], vec![2]];
+        assert_eq!(count_paths(&adj, 0, 1), Ok(1));
+        let adj = vec![vec![1, 2], vec![], vec![3], vec![2]];
+        assert_eq!(count_paths(&adj, 0, 1), Ok(1));
+        // a cycle through `to` itself ends each path at its first arrival
+        let adj = vec![vec![1], vec![2], vec![1]];
+        assert_eq!(count_paths(&adj, 0, 1), Ok(1));
+    }
+
+    #[test]
+    fn test_waypoints() {
+        // a diamond 0 -> {1, 2} -> 3 followed by another 3 -> {4, 5} -> 6
+        let adj = vec![
+            vec![1, 2],
+            vec![3],
+            vec![3],
+            vec![4, 5],
+            vec![6],
+            vec![6],
+            vec![],
+        ];
+        let query = |waypoints: Vec<usize>, ordered, avoid| PathQuery {
+            waypoints,
+            ordered,
+            avoid,
+        };
+        let count = |q| count_paths_through(&adj, 0, 6, &q).unwrap();
+        assert_eq!(count(query(vec![], false, vec![])), 4);
+        assert_eq!(count(query(vec![5, 1], false, vec![])), 1);
+        assert_eq!(count(query(vec![5, 1], true, vec![])), 0);
+        assert_eq!(count(query(vec![1, 5], true, vec![])), 1);
+        assert_eq!(count(query(vec![3], false, vec![4])), 2);
+        assert_eq!(count(query(vec![4], false, vec![4])), 0);
+        // a repeated waypoint could never be satisfied twice
+        assert!(std::panic::catch_unwind(|| {
+            count(query(vec![1, 1], false, vec![]))
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_graph() {
+        let graph = Graph::parse("a: b c\nb -> d, c\nc-d\n", true).unwrap();
+        let id = |name| graph.id(name).unwrap();
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.neighbours(id("b")).collect::<Vec<_>>(), [3, 2]);
+        assert_eq!(graph.topological_sort(), Ok(vec![0, 1, 2, 3]));
+        assert_eq!(graph.reachable(id("c")), [false, false, true, true]);
+        assert_eq!(graph.reversed().neighbours(id("d")).count(), 2);
+        assert_eq!(
+            graph.count_paths(id("a"), id("d"), &PathQuery::default()),
+            Ok(3)
+        );
+
+        let mut cyclic = graph.clone();
+        cyclic.add_edge(id("d"), id("b"), 1);
+        let cycle = cyclic.topological_sort().unwrap_err();
+        assert_eq!(cyclic.describe(&cycle), "cycle b -> d -> b");
+        assert_eq!(cyclic.sccs(), [vec![2, 3, 1], vec![0]]);
+
+        let undirected = Graph::parse("a-b\nc-b", false).unwrap();
+        assert_eq!(undirected.neighbours(1).collect::<Vec<_>>(), [0, 2]);
+        assert_eq!(
+            Graph::parse("a: b\n\nc d\n", true),
+            Err(NoEdge {
+                line: 3,
+                text: "c d".to_string()
+            })
+        );
+        for line in ["a:", "a ->", "a - ", ": b"] {
+            assert_eq!(
+                Graph::parse(&format!("x: y\n{line}"), true),
+                Err(NoEdge {
+                    line: 2,
+                    text: line.trim().to_string()
+                })
+            );
+        }
+    }
+}
@@ -0,0 +1,353 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IlpError {
+    /// No non-negative integer assignment satisfies the constraints.
+    Infeasible,
+    /// The variable is free after elimination but has no upper bound, so
+    /// the search over it would never end.
+    Unbounded(usize),
+}
+
+impl Display for IlpError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IlpError::Infeasible => write!(f, "the system has no solution"),
+            IlpError::Unbounded(var) => {
+                write!(f, "variable {var} is free and has no upper bound")
+            }
+        }
+    }
+}
+
+impl Error for IlpError {}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IlpSolution {
+    pub objective: i64,
+    pub values: Vec<i64>,
+}
+
+/// Minimises `cost · x` subject to `A x = b` over non-negative integers,
+/// optionally bounded from above.
+///
+/// Meant for the small systems puzzles produce: exact rational elimination
+/// leaves only a handful of free variables, which are then searched
+/// exhaustively within their bounds. Bounds that are not given explicitly
+/// are derived from constraints whose coefficients are all non-negative.
+#[derive(Debug, Clone)]
+pub struct IntegerProgram {
+    rows: Vec<(Vec<i64>, i64)>,
+    upper: Vec<Option<i64>>,
+    cost: Vec<i64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Ratio {
+    num: i128,
+    den: i128,
+}
+
+fn gcd(a: i128, b: i128) -> i128 {
+    if b == 0 {
+        a.abs()
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl Ratio {
+    fn new(num: i128, den: i128) -> Self {
+        let g = gcd(num, den).max(1) * den.signum();
+        Self {
+            num: num / g,
+            den: den / g,
+        }
+    }
+
+    fn int(n: i64) -> Self {
+        Self::new(n as i128, 1)
+    }
+
+    fn is_zero(self) -> bool {
+        self.num == 0
+    }
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(
+            self.num * other.den - other.num * self.den,
+            self.den * other.den,
+        )
+    }
+
+    fn mul(self, other: Self) -> Self {
+        Self::new(self.num * other.num, self.den * other.den)
+    }
+
+    fn div(self, other: Self) -> Self {
+        Self::new(self.num * other.den, self.den * other.num)
+    }
+}
+
+/// A pivot row scaled back to integers: `den * x[pivot] + Σ free[f] * x[f]
+/// = rhs`, with `free` indexed like the search order.
+struct Row {
+    pivot: usize,
+    den: i128,
+    free: Vec<i128>,
+    rhs: i128,
+}
+
+struct Search<'a> {
+    program: &'a IntegerProgram,
+    rows: Vec<Row>,
+    free_vars: Vec<usize>,
+    // rows whose value is fully determined once free variable k is assigned
+    settled_at: Vec<Vec<usize>>,
+    values: Vec<i64>,
+    best: Option<IlpSolution>,
+}
+
+impl Search<'_> {
+    fn pivot_value(&self, row: &Row, residual: i128) -> Option<i64> {
+        if residual % row.den != 0 {
+            return None;
+        }
+        let value = (residual / row.den) as i64;
+        let upper = self.program.upper[row.pivot].unwrap_or(i64::MAX);
+        (0..=upper).contains(&value).then_some(value)
+    }
+
+    fn dfs(&mut self, k: usize, residuals: &mut Vec<i128>, partial: i64) {
+        if let Some(best) = &self.best {
+            if self.program.cost.iter().all(|&c| c >= 0)
+                && partial >= best.objective
+            {
+                return;
+            }
+        }
+        if k == self.free_vars.len() {
+            let mut objective = partial;
+            for (row, &residual) in self.rows.iter().zip(residuals.iter()) {
+                let value = self.pivot_value(row, residual).unwrap();
+                objective += self.program.cost[row.pivot] * value;
+                self.values[row.pivot] = value;
+            }
+            if self.best.as_ref().is_none_or(|b| objective < b.objective) {
+                self.best = Some(IlpSolution {
+                    objective,
+                    values: self.values.clone(),
+                });
+            }
+            return;
+        }
+
+        let var = self.free_vars[k];
+        let upper = self.program.upper[var].unwrap();
+        for value in 0..=upper {
+            self.values[var] = value;
+            for (r, row) in self.rows.iter().enumerate() {
+                residuals[r] -= row.free[k] * value as i128;
+            }
+            let settled = self.settled_at[k].iter().all(|&r| {
+                self.pivot_value(&self.rows[r], residuals[r]).is_some()
+            });
+            if settled {
+                let cost = self.program.cost[var] * value;
+                self.dfs(k + 1, residuals, partial + cost);
+            }
+            for (r, row) in self.rows.iter().enumerate() {
+                residuals[r] += row.free[k] * value as i128;
+            }
+        }
+    }
+}
+
+impl IntegerProgram {
+    /// A feasibility problem over `vars` variables; use `minimise` to give
+    /// it an objective.
+    pub fn new(vars: usize) -> Self {
+        Self {
+            rows: vec![],
+            upper: vec![None; vars],
+            cost: vec![0; vars],
+        }
+    }
+
+    pub fn minimise(mut self, cost: Vec<i64>) -> Self {
+        assert_eq!(cost.len(), self.cost.len(), "one cost per variable");
+        self.cost = cost;
+        self
+    }
+
+    pub fn add_equality(&mut self, coeffs: Vec<i64>, rhs: i64) {
+        assert_eq!(coeffs.len(), self.cost.len(), "one coeff per variable");
+        self.rows.push((coeffs, rhs));
+    }
+
+    pub fn set_upper_bound(&mut self, var: usize, upper: i64) {
+        self.upper[var] = Some(upper);
+    }
+
+    fn derive_bounds(&mut self) {
+        for (coeffs, rhs) in &self.rows {
+            if coeffs.iter().any(|&c| c < 0) {
+                continue;
+            }
+            for (var, &c) in coeffs.iter().enumerate() {
+                if c > 0 {
+                    let bound = rhs.div_euclid(c);
+                    let upper = self.upper[var].get_or_insert(bound);
+                    *upper = (*upper).min(bound);
+                }
+            }
+        }
+    }
+
+    pub fn solve(&self) -> Result<IlpSolution, IlpError> {
+        let mut program = self.clone();
+        program.derive_bounds();
+        let n = program.cost.len();
+
+        let mut matrix = program
+            .rows
+            .iter()
+            .map(|(coeffs, rhs)| {
+                coeffs
+                    .iter()
+                    .chain([rhs])
+                    .map(|&c| Ratio::int(c))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        let mut pivots = vec![];
+        for col in 0..n {
+            let r = pivots.len();
+            let Some(p) =
+                (r..matrix.len()).find(|&i| !matrix[i][col].is_zero())
+            else {
+                continue;
+            };
+            matrix.swap(r, p);
+            let lead = matrix[r][col];
+            for c in matrix[r].iter_mut() {
+                *c = c.div(lead);
+            }
+            let pivot_row = matrix[r].clone();
+            for (i, row) in matrix.iter_mut().enumerate() {
+                let factor = row[col];
+                if i == r || factor.is_zero() {
+                    continue;
+                }
+                for (c, &p) in row.iter_mut().zip(&pivot_row) {
+                    *c = c.sub(factor.mul(p));
+                }
+            }
+            pivots.push(col);
+        }
+        if matrix[pivots.len()..].iter().any(|row| !row[n].is_zero()) {
+            return Err(IlpError::Infeasible);
+        }
+
+        let free_vars =
+            (0..n).filter(|v| !pivots.contains(v)).collect::<Vec<_>>();
+        if let Some(&var) =
+            free_vars.iter().find(|&&v| program.upper[v].is_none())
+        {
+            return Err(IlpError::Unbounded(var));
+        }
+
+        let rows = pivots
+            .iter()
+            .enumerate()
+            .map(|(r, &pivot)| {
+                let row = &matrix[r];
+                let lcm =
+                    row.iter().fold(1, |acc, c| acc / gcd(acc, c.den) * c.den);
+                let scale = |c: Ratio| c.num * (lcm / c.den);
+                Row {
+                    pivot,
+                    den: lcm,
+                    free: free_vars.iter().map(|&f| scale(row[f])).collect(),
+                    rhs: scale(row[n]),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let mut settled_at = vec![vec![]; free_vars.len()];
+        let mut constant_rows = vec![];
+        for (r, row) in rows.iter().enumerate() {
+            match row.free.iter().rposition(|&c| c != 0) {
+                Some(k) => settled_at[k].push(r),
+                None => constant_rows.push(r),
+            }
+        }
+
+        let mut search = Search {
+            program: &program,
+            rows,
+            free_vars,
+            settled_at,
+            values: vec![0; n],
+            best: None,
+        };
+        let mut residuals =
+            search.rows.iter().map(|row| row.rhs).collect::<Vec<_>>();
+        let consistent = constant_rows.iter().all(|&r| {
+            search.pivot_value(&search.rows[r], residuals[r]).is_some()
+        });
+        if consistent {
+            search.dfs(0, &mut residuals, 0);
+        }
+        search.best.ok_or(IlpError::Infeasible)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimise_presses() {
+        // (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}
+        let buttons = [
+            vec![3],
+            vec![1, 3],
+            vec![2],
+            vec![2, 3],
+            vec![0, 2],
+            vec![0, 1],
+        ];
+        let mut program =
+            IntegerProgram::new(buttons.len()).minimise(vec![1; 6]);
+        for (counter, target) in [3, 5, 4, 7].into_iter().enumerate() {
+            let coeffs = buttons
+                .iter()
+                .map(|b| i64::from(b.contains(&counter)))
+                .collect();
+            program.add_equality(coeffs, target);
+        }
+        let solution = program.solve().unwrap();
+        assert_eq!(solution.objective, 10);
+        assert_eq!(solution.values.iter().sum::<i64>(), 10);
+    }
+
+    #[test]
+    fn test_fractional_pivots() {
+        // 2x + 3y = 12 has 2x = 12 - 3y, so only even y survive
+        let mut program = IntegerProgram::new(2).minimise(vec![1, 1]);
+        program.add_equality(vec![2, 3], 12);
+        let solution = program.solve().unwrap();
+        assert_eq!(solution.values, vec![0, 4]);
+
+        let mut program = IntegerProgram::new(2);
+        program.add_equality(vec![2, 4], 7);
+        assert_eq!(program.solve(), Err(IlpError::Infeasible));
+
+        let mut program = IntegerProgram::new(2);
+        program.add_equality(vec![1, -1], 3);
+        assert_eq!(program.solve(), Err(IlpError::Unbounded(1)));
+    }
+}
@@ -0,0 +1,199 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug)]
+pub enum InputError {
+    Missing(PathBuf),
+    Unreadable(PathBuf, io::Error),
+    Stdin(io::Error),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Missing(path) => write!(
+                f,
+                "no input at {}: save the puzzle input there, pass a path \
+                 or pass - to read stdin",
+                path.display()
+            ),
+            InputError::Unreadable(path, e) => {
+                write!(f, "cannot read {}: {e}", path.display())
+            }
+            InputError::Stdin(e) => write!(f, "cannot read stdin: {e}"),
+        }
+    }
+}
+
+impl Error for InputError {}
+
+/// The directory holding `inputs/`, i.e. the workspace root.
+pub fn root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("common lives inside the workspace")
+        .to_path_buf()
+}
+
+/// The calendar whose days sit at the top of the workspace; other years
+/// live under `yYYYY/`.
+pub const YEAR: u16 = 2025;
+
+/// Where a day's input, answers and examples are kept. Other calendars get
+/// a directory of their own so they never shadow this one's.
+pub fn dir(year: u16, day: u8) -> PathBuf {
+    root().join(relative_dir(year, day))
+}
+
+/// `dir` relative to the workspace root.
+pub fn relative_dir(year: u16, day: u8) -> PathBuf {
+    match year {
+        YEAR => format!("inputs/day{day}").into(),
+        _ => format!("inputs/{year}/day{day}").into(),
+    }
+}
+
+pub fn default_path(year: u16, day: u8) -> PathBuf {
+    dir(year, day).join("input.txt")
+}
+
+pub fn examples_dir(year: u16, day: u8) -> PathBuf {
+    dir(year, day).join("examples")
+}
+
+/// The `n`th example block of the day's puzzle page, exactly as extracted
+/// by `aoc examples`. Meant for tests, so a missing fixture panics.
+pub fn example(year: u16, day: u8, n: usize) -> String {
+    let path = examples_dir(year, day).join(format!("{n}.txt"));
+    std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "cannot read {}: {e}; extract the examples with `aoc examples \
+             {day} <saved page>`",
+            path.display()
+        )
+    })
+}
+
+/// The answer the puzzle page gives for `part` of its examples, as saved
+/// to `answers.toml` by `aoc examples`. Also meant for tests and panics
+/// when the answer is missing or does not parse as `T`.
+pub fn example_answer<T>(year: u16, day: u8, part: u8) -> T
+where
+    T: FromStr,
+    T::Err: std::fmt::Debug,
+{
+    let path = examples_dir(year, day).join("answers.toml");
+    let text = std::fs::read_to_string(&path)
+        .unwrap_or_else(|e| panic!("cannot read {}: {e}", path.display()));
+    let answer = text
+        .lines()
+        .filter_map(|line| line.split_once('='))
+        .find(|(key, _)| key.trim() == format!("part{part}"))
+        .map(|(_, value)| value.trim().trim_matches('"'))
+        .unwrap_or_else(|| {
+            panic!("{} has no answer for part {part}", path.display())
+        });
+    answer.parse().unwrap_or_else(|e| {
+        panic!("part {part} answer {answer:?} in {}: {e:?}", path.display())
+    })
+}
+
+/// Reads the input from `path`, from stdin when it is `-`, or from the
+/// day's default location when no path is given.
+pub fn load(
+    year: u16,
+    day: u8,
+    path: Option<&str>,
+) -> Result<String, InputError> {
+    let path = match path {
+        Some("-") => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(InputError::Stdin)?;
+            return Ok(input);
+        }
+        Some(path) => PathBuf::from(path),
+        None => default_path(year, day),
+    };
+    std::fs::read_to_string(&path).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound => InputError::Missing(path),
+        _ => InputError::Unreadable(path, e),
+    })
+}
+
+/// The first argument that is not an option, skipping the values of the
+/// options listed in `valued`.
+pub fn path_arg<'a>(args: &'a [String], valued: &[&str]) -> Option<&'a str> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if valued.contains(&arg.as_str()) {
+            args.next();
+        } else if arg == "-" || !arg.starts_with("--") {
+            return Some(arg);
+        }
+    }
+    None
+}
+
+/// The input named on the command line of a day's binary. Exits with the
+/// reason when it cannot be read.
+pub fn from_args(year: u16, day: u8, valued: &[&str]) -> String {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    load(year, day, path_arg(&args, valued)).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(1)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_path_arg() {
+        let args = |line: &str| {
+            line.split_whitespace()
+                .map(String::from)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(path_arg(&args("--dot"), &[]), None);
+        assert_eq!(path_arg(&args("--dot big.txt"), &[]), Some("big.txt"));
+        assert_eq!(path_arg(&args("- --dot"), &[]), Some("-"));
+        assert_eq!(
+            path_arg(&args("--out r.txt --render mine.txt"), &["--out"]),
+            Some("mine.txt")
+        );
+    }
+
+    #[test]
+    fn test_missing_input() {
+        let error = load(YEAR, 1, Some("no/such/input.txt")).unwrap_err();
+        assert!(matches!(error, InputError::Missing(_)));
+        assert!(error
+            .to_string()
+            .starts_with("no input at no/such/input.txt"));
+    }
+
+    #[test]
+    fn test_example_answer() {
+        assert_eq!(example_answer::<u64>(YEAR, 2, 2), 4174379265);
+        assert_eq!(example_answer::<String>(YEAR, 11, 1), "5");
+        assert!(std::panic::catch_unwind(|| {
+            example_answer::<u64>(YEAR, 12, 2);
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_dir() {
+        assert_eq!(dir(YEAR, 7), root().join("inputs/day7"));
+        assert_eq!(
+            default_path(2017, 3),
+            root().join("inputs/2017/day3/input.txt")
+        );
+    }
+}
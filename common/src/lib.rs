@@ -1,3 +1,14 @@
+pub mod dlx;
+pub mod dot;
+pub mod geometry;
+pub mod gf2;
+pub mod graph;
+pub mod ilp;
+pub mod input;
+pub mod polygon;
+pub mod polyomino;
+pub mod solution;
+
 use std::fmt::{Display, Formatter};
 use std::ops::{Index, IndexMut};
 
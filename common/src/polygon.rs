@@ -0,0 +1,219 @@
+use crate::geometry::{CompressedPolygon, Point};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolygonError {
+    TooFewVertices(usize),
+    RepeatedVertex(Point),
+    NotAxisAligned(Point, Point),
+    SelfIntersecting(usize, usize),
+}
+
+impl Display for PolygonError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolygonError::TooFewVertices(n) => {
+                write!(
+                    f,
+                    "a rectilinear polygon needs at least 4 vertices, got {n}"
+                )
+            }
+            PolygonError::RepeatedVertex(p) => {
+                write!(f, "vertex {p:?} is repeated by the next one")
+            }
+            PolygonError::NotAxisAligned(a, b) => {
+                write!(f, "edge {a:?} -> {b:?} is not axis-aligned")
+            }
+            PolygonError::SelfIntersecting(i, j) => {
+                write!(f, "edges {i} and {j} intersect")
+            }
+        }
+    }
+}
+
+impl Error for PolygonError {}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Location {
+    Inside,
+    Boundary,
+    Outside,
+}
+
+/// A simple rectilinear polygon on integer coordinates, given by its
+/// vertices in walking order. The closing edge from the last vertex back to
+/// the first is implied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Polygon {
+    vertices: Vec<Point>,
+}
+
+fn bounds(a: Point, b: Point) -> (Point, Point) {
+    ((a.0.min(b.0), a.1.min(b.1)), (a.0.max(b.0), a.1.max(b.1)))
+}
+
+/// Overlap of two axis-aligned segments as the number of shared lattice
+/// points, 0 when they are disjoint.
+fn overlap(e1: (Point, Point), e2: (Point, Point)) -> isize {
+    let (lo1, hi1) = bounds(e1.0, e1.1);
+    let (lo2, hi2) = bounds(e2.0, e2.1);
+    let dx = hi1.0.min(hi2.0) - lo1.0.max(lo2.0) + 1;
+    let dy = hi1.1.min(hi2.1) - lo1.1.max(lo2.1) + 1;
+    if dx <= 0 || dy <= 0 {
+        return 0;
+    }
+    dx * dy
+}
+
+impl Polygon {
+    pub fn new(vertices: Vec<Point>) -> Result<Self, PolygonError> {
+        let n = vertices.len();
+        if n < 4 {
+            return Err(PolygonError::TooFewVertices(n));
+        }
+        let polygon = Self { vertices };
+        let edges = polygon.edges().collect::<Vec<_>>();
+        for &(a, b) in &edges {
+            if a == b {
+                return Err(PolygonError::RepeatedVertex(a));
+            }
+            if a.0 != b.0 && a.1 != b.1 {
+                return Err(PolygonError::NotAxisAligned(a, b));
+            }
+        }
+        for i in 0..n {
+            for j in i + 1..n {
+                // neighbouring edges share exactly their common vertex
+                let adjacent = j == i + 1 || (i == 0 && j == n - 1);
+                let allowed = if adjacent { 1 } else { 0 };
+                if overlap(edges[i], edges[j]) > allowed {
+                    return Err(PolygonError::SelfIntersecting(i, j));
+                }
+            }
+        }
+        Ok(polygon)
+    }
+
+    pub fn vertices(&self) -> &[Point] {
+        &self.vertices
+    }
+
+    pub fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        let n = self.vertices.len();
+        (0..n).map(move |i| (self.vertices[i], self.vertices[(i + 1) % n]))
+    }
+
+    /// Shoelace area, positive when the vertices run counter-clockwise in a
+    /// y-up frame (clockwise on screen, where y grows downwards).
+    pub fn signed_area(&self) -> isize {
+        let twice: isize =
+            self.edges().map(|(a, b)| a.0 * b.1 - b.0 * a.1).sum();
+        twice / 2
+    }
+
+    pub fn area(&self) -> isize {
+        self.signed_area().abs()
+    }
+
+    /// Lattice points on the boundary, which for an axis-aligned polygon is
+    /// also its perimeter.
+    pub fn boundary_points(&self) -> isize {
+        self.edges()
+            .map(|(a, b)| (a.0.abs_diff(b.0) + a.1.abs_diff(b.1)) as isize)
+            .sum()
+    }
+
+    pub fn perimeter(&self) -> isize {
+        self.boundary_points()
+    }
+
+    /// Lattice points strictly inside, from Pick's theorem `A = I + B/2 - 1`.
+    pub fn interior_points(&self) -> isize {
+        self.area() - self.boundary_points() / 2 + 1
+    }
+
+    /// Every lattice point inside or on the boundary, i.e. the number of
+    /// tiles the loop encloses including the loop itself.
+    pub fn lattice_points(&self) -> isize {
+        self.interior_points() + self.boundary_points()
+    }
+
+    pub fn locate(&self, p: Point) -> Location {
+        if self.edges().any(|e| overlap(e, (p, p)) > 0) {
+            return Location::Boundary;
+        }
+        // cast a ray towards +x and count the vertical edges it crosses,
+        // treating each edge as half-open in y so vertices count once
+        let crossings = self
+            .edges()
+            .filter(|&(a, b)| a.0 == b.0 && a.0 > p.0)
+            .filter(|&(a, b)| a.1.min(b.1) <= p.1 && p.1 < a.1.max(b.1))
+            .count();
+        if crossings % 2 == 1 {
+            Location::Inside
+        } else {
+            Location::Outside
+        }
+    }
+
+    pub fn compress(&self) -> CompressedPolygon {
+        CompressedPolygon::new(&self.vertices)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Polygon {
+        Polygon::new(vec![
+            (7, 1),
+            (11, 1),
+            (11, 7),
+            (9, 7),
+            (9, 5),
+            (2, 5),
+            (2, 3),
+            (7, 3),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn test_measures() {
+        let polygon = example();
+        assert_eq!(polygon.signed_area(), 30);
+        assert_eq!(polygon.boundary_points(), 30);
+        assert_eq!(polygon.interior_points(), 16);
+        assert_eq!(polygon.lattice_points(), 46);
+        assert_eq!(polygon.locate((8, 2)), Location::Inside);
+        assert_eq!(polygon.locate((9, 6)), Location::Boundary);
+        assert_eq!(polygon.locate((3, 2)), Location::Outside);
+        assert_eq!(polygon.locate((7, 4)), Location::Inside);
+    }
+
+    #[test]
+    fn test_validation() {
+        assert_eq!(
+            Polygon::new(vec![(0, 0), (2, 0), (2, 2)]),
+            Err(PolygonError::TooFewVertices(3))
+        );
+        assert_eq!(
+            Polygon::new(vec![(0, 0), (2, 0), (3, 2), (0, 2)]),
+            Err(PolygonError::NotAxisAligned((2, 0), (3, 2)))
+        );
+        // the first edge crosses the fourth one
+        assert_eq!(
+            Polygon::new(vec![
+                (0, 0),
+                (0, 2),
+                (2, 2),
+                (2, 1),
+                (-1, 1),
+                (-1, 0)
+            ]),
+            Err(PolygonError::SelfIntersecting(0, 3))
+        );
+    }
+}
@@ -0,0 +1,222 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// A `(row, col)` cell.
+pub type Cell = (usize, usize);
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolyominoError {
+    Empty,
+    /// A character other than `#` or `.` at a 1-based line and column.
+    Unexpected {
+        line: usize,
+        column: usize,
+        found: char,
+    },
+}
+
+impl Display for PolyominoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PolyominoError::Empty => write!(f, "shape has no cells"),
+            PolyominoError::Unexpected {
+                line,
+                column,
+                found,
+            } => {
+                write!(f, "line {line}, column {column}: unexpected {found:?}")
+            }
+        }
+    }
+}
+
+impl Error for PolyominoError {}
+
+/// A set of cells translated so its bounding box starts at the origin.
+///
+/// Cells are kept sorted in reading order, which makes the derived
+/// equality the fixed identity (same shape up to translation) and lets
+/// the smallest orientation serve as the free canonical form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Polyomino {
+    cells: Vec<Cell>,
+}
+
+impl Polyomino {
+    pub fn new(cells: impl IntoIterator<Item = Cell>) -> Self {
+        let cells = cells.into_iter().collect::<Vec<_>>();
+        let min_row = cells.iter().map(|&(r, _)| r).min().unwrap_or(0);
+        let min_col = cells.iter().map(|&(_, c)| c).min().unwrap_or(0);
+        let mut cells = cells
+            .into_iter()
+            .map(|(r, c)| (r - min_row, c - min_col))
+            .collect::<Vec<_>>();
+        cells.sort();
+        cells.dedup();
+        Self { cells }
+    }
+
+    pub fn cells(&self) -> &[Cell] {
+        &self.cells
+    }
+
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.iter().map(|&(_, c)| c + 1).max().unwrap_or(0)
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.last().map_or(0, |&(r, _)| r + 1)
+    }
+
+    pub fn contains(&self, cell: Cell) -> bool {
+        self.cells.binary_search(&cell).is_ok()
+    }
+
+    /// Unit edges on the outline, holes included.
+    pub fn perimeter(&self) -> usize {
+        let shared = self
+            .cells
+            .iter()
+            .map(|&(r, c)| {
+                usize::from(self.contains((r + 1, c)))
+                    + usize::from(self.contains((r, c + 1)))
+            })
+            .sum::<usize>();
+        4 * self.area() - 2 * shared
+    }
+
+    /// Quarter turn clockwise.
+    pub fn rotate(&self) -> Self {
+        let height = self.height();
+        Self::new(self.cells.iter().map(|&(r, c)| (c, height - 1 - r)))
+    }
+
+    /// Mirror image across the vertical axis.
+    pub fn flip(&self) -> Self {
+        let width = self.width();
+        Self::new(self.cells.iter().map(|&(r, c)| (r, width - 1 - c)))
+    }
+
+    /// The distinct fixed shapes reachable by rotating and flipping,
+    /// smallest first.
+    pub fn orientations(&self) -> Vec<Self> {
+        let mut all = vec![];
+        for mut shape in [self.clone(), self.flip()] {
+            for _ in 0..4 {
+                let next = shape.rotate();
+                all.push(shape);
+                shape = next;
+            }
+        }
+        all.sort();
+        all.dedup();
+        all
+    }
+
+    pub fn canonical(&self) -> Self {
+        self.orientations().swap_remove(0)
+    }
+
+    /// Whether the two are the same free polyomino, i.e. equal once
+    /// rotations and reflections are allowed.
+    pub fn same_free(&self, other: &Self) -> bool {
+        self.area() == other.area() && self.canonical() == other.canonical()
+    }
+}
+
+impl FromStr for Polyomino {
+    type Err = PolyominoError;
+
+    /// Parses the `#`/`.` notation; short lines are padded with `.`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut cells = vec![];
+        for (r, line) in s.lines().enumerate() {
+            for (c, ch) in line.chars().enumerate() {
+                match ch {
+                    '#' => cells.push((r, c)),
+                    '.' => {}
+                    found => {
+                        return Err(PolyominoError::Unexpected {
+                            line: r + 1,
+                            column: c + 1,
+                            found,
+                        })
+                    }
+                }
+            }
+        }
+        if cells.is_empty() {
+            return Err(PolyominoError::Empty);
+        }
+        Ok(Self::new(cells))
+    }
+}
+
+impl Display for Polyomino {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let width = self.width();
+        for r in 0..self.height() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            let line = (0..width)
+                .map(|c| if self.contains((r, c)) { '#' } else { '.' })
+                .collect::<String>();
+            write!(f, "{line}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_shape() {
+        let shape = indoc! {"
+            ###.
+            #..#
+            ####"
+        }
+        .parse::<Polyomino>()
+        .unwrap();
+        assert_eq!((shape.width(), shape.height()), (4, 3));
+        assert_eq!(shape.area(), 9);
+        assert_eq!(shape.perimeter(), 4 * 9 - 2 * 8);
+        assert_eq!(shape.to_string(), "###.\n#..#\n####");
+        assert_eq!(shape.rotate().to_string(), "###\n#.#\n#.#\n##.");
+        assert_eq!(shape.orientations().len(), 8);
+
+        assert_eq!(
+            "..#\n.x#".parse::<Polyomino>(),
+            Err(PolyominoError::Unexpected {
+                line: 2,
+                column: 2,
+                found: 'x'
+            })
+        );
+        assert_eq!("...".parse::<Polyomino>(), Err(PolyominoError::Empty));
+    }
+
+    #[test]
+    fn test_identity() {
+        let s = ".##\n##.".parse::<Polyomino>().unwrap();
+        let z = "##.\n.##".parse::<Polyomino>().unwrap();
+        let moved = Polyomino::new(s.cells().iter().map(|&(r, c)| (r + 5, c)));
+        assert_eq!(s, moved);
+        assert_ne!(s, z);
+        assert!(s.same_free(&z));
+        assert_eq!(s.orientations().len(), 4);
+
+        let square = "##\n##".parse::<Polyomino>().unwrap();
+        assert_eq!(square.orientations().len(), 1);
+        assert!(!square.same_free(&s));
+    }
+}
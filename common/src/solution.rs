@@ -0,0 +1,187 @@
+use crate::input;
+use std::fmt::Display;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Each requested part with its answer rendered as text, or the reason
+/// the part could not be answered.
+pub type Answers = Vec<(u8, Result<String, String>)>;
+
+/// What a part returns. Plain values are printed as they are; a part that
+/// can fail returns a `Result` and its error is reported, not panicked.
+pub trait Answer {
+    fn render(&self) -> Result<String, String>;
+}
+
+macro_rules! plain_answer {
+    ($($t:ty),*) => {
+        $(impl Answer for $t {
+            fn render(&self) -> Result<String, String> {
+                Ok(self.to_string())
+            }
+        })*
+    };
+}
+
+plain_answer!(i16, i32, i64, isize, u32, u64, u128, usize, String, &str);
+
+impl<T: Display, E: Display> Answer for Result<T, E> {
+    fn render(&self) -> Result<String, String> {
+        match self {
+            Ok(answer) => Ok(answer.to_string()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// One day of the calendar. The input is parsed once and both parts are
+/// answered from the result.
+pub trait Solution {
+    type Parsed<'a>;
+    type Answer1: Answer;
+    type Answer2: Answer;
+
+    const YEAR: u16 = input::YEAR;
+    const DAY: u8;
+    /// The last day of the calendar only has one puzzle.
+    const PARTS: u8 = 2;
+
+    fn parse(input: &str) -> Self::Parsed<'_>;
+    fn part1(parsed: &Self::Parsed<'_>) -> Self::Answer1;
+    fn part2(parsed: &Self::Parsed<'_>) -> Self::Answer2;
+
+    /// Answers the requested parts, skipping those the day does not have.
+    fn answers(input: &str, parts: &[u8]) -> Answers {
+        let parsed = Self::parse(input);
+        parts
+            .iter()
+            .filter(|&&part| part <= Self::PARTS)
+            .map(|&part| match part {
+                1 => (part, Self::part1(&parsed).render()),
+                2 => (part, Self::part2(&parsed).render()),
+                _ => panic!("there is no part {part}"),
+            })
+            .collect()
+    }
+
+    /// Runs every stage once, timing each on its own.
+    fn time(input: &str) -> Timings {
+        let started = Instant::now();
+        let parsed = black_box(Self::parse(black_box(input)));
+        let parse = started.elapsed();
+        let started = Instant::now();
+        black_box(Self::part1(&parsed));
+        let part1 = started.elapsed();
+        let part2 = (Self::PARTS > 1).then(|| {
+            let started = Instant::now();
+            black_box(Self::part2(&parsed));
+            started.elapsed()
+        });
+        Timings {
+            parse,
+            part1,
+            part2,
+        }
+    }
+}
+
+/// How long one run spent in each stage; `part2` is absent on days with a
+/// single part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    pub parse: Duration,
+    pub part1: Duration,
+    pub part2: Option<Duration>,
+}
+
+/// A `Solution` with its types erased, so a runner can keep every day in
+/// one table.
+#[derive(Debug, Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    pub parts: u8,
+    answers: fn(&str, &[u8]) -> Answers,
+    time: fn(&str) -> Timings,
+}
+
+impl Puzzle {
+    pub fn of<S: Solution>() -> Self {
+        Self {
+            year: S::YEAR,
+            day: S::DAY,
+            parts: S::PARTS,
+            answers: S::answers,
+            time: S::time,
+        }
+    }
+
+    pub fn answers(&self, input: &str, parts: &[u8]) -> Answers {
+        (self.answers)(input, parts)
+    }
+
+    pub fn time(&self, input: &str) -> Timings {
+        (self.time)(input)
+    }
+}
+
+/// Prints every part, as each day's own binary does, exiting with an error
+/// when a part could not be answered.
+pub fn run<S: Solution>(input: &str) {
+    let mut failed = false;
+    for (part, answer) in S::answers(input, &[1, 2]) {
+        match answer {
+            Ok(answer) => println!("part {part}: {answer}"),
+            Err(e) => {
+                failed = true;
+                eprintln!("part {part}: {e}");
+            }
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Lengths;
+
+    impl Solution for Lengths {
+        type Parsed<'a> = Vec<&'a str>;
+        type Answer1 = usize;
+        type Answer2 = String;
+
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 25;
+        const PARTS: u8 = 1;
+
+        fn parse(input: &str) -> Vec<&str> {
+            input.lines().collect()
+        }
+
+        fn part1(lines: &Vec<&str>) -> usize {
+            lines.iter().map(|line| line.len()).sum()
+        }
+
+        fn part2(_: &Vec<&str>) -> String {
+            unreachable!()
+        }
+    }
+
+    #[test]
+    fn test_puzzle() {
+        let puzzle = Puzzle::of::<Lengths>();
+        assert_eq!((puzzle.year, puzzle.day, puzzle.parts), (2015, 25, 1));
+        assert_eq!(
+            puzzle.answers("ab\ncde", &[1, 2]),
+            [(1, Ok("5".to_string()))]
+        );
+        assert_eq!(puzzle.time("ab").part2, None);
+
+        let failed: Result<usize, &str> = Err("no solution");
+        assert_eq!(failed.render(), Err("no solution".to_string()));
+    }
+}
@@ -0,0 +1,90 @@
+use common::solution::Solution;
+
+const START_POINT: i32 = 50;
+const MOD: i32 = 100;
+
+/// Each rotation as a signed number of clicks, left being negative.
+fn parse(input: &str) -> Vec<i32> {
+    input
+        .lines()
+        .map(|line| {
+            let (dir_str, n_str) = line.split_at(1);
+            let steps: i32 = n_str.parse().unwrap_or(0);
+            match dir_str {
+                "L" => -steps,
+                "R" => steps,
+                _ => unreachable!("invalid direction"),
+            }
+        })
+        .collect()
+}
+
+fn part1(rotations: &[i32]) -> i32 {
+    let mut pos = START_POINT;
+    let mut count = 0;
+
+    for &step in rotations {
+        pos = (pos + step).rem_euclid(MOD);
+        if pos == 0 {
+            count += 1;
+        }
+    }
+
+    count
+}
+
+fn part2(rotations: &[i32]) -> i16 {
+    let mut pos = START_POINT;
+    let mut count = 0;
+
+    for &steps in rotations {
+        for _ in 0..steps.abs() {
+            pos = (pos + steps.signum()).rem_euclid(MOD);
+            if pos == 0 {
+                count += 1;
+            }
+        }
+    }
+
+    count
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Parsed<'a> = Vec<i32>;
+    type Answer1 = i32;
+    type Answer2 = i16;
+
+    const DAY: u8 = 1;
+
+    fn parse(input: &str) -> Vec<i32> {
+        parse(input)
+    }
+
+    fn part1(rotations: &Vec<i32>) -> i32 {
+        part1(rotations)
+    }
+
+    fn part2(rotations: &Vec<i32>) -> i16 {
+        part2(rotations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+
+    #[test]
+    fn test_part1() {
+        let input = &example(2025, 1, 1);
+        assert_eq!(part1(&parse(input)), example_answer(2025, 1, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = &example(2025, 1, 1);
+        assert_eq!(part2(&parse(input)), example_answer(2025, 1, 2));
+    }
+}
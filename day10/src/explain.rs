@@ -0,0 +1,65 @@
+use crate::machine::Machine;
+use std::fmt::{Display, Formatter};
+
+/// How many times each button of a machine is pressed, in button order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Explanation<'a> {
+    pub machine: &'a Machine,
+    pub presses: Vec<usize>,
+}
+
+impl<'a> Explanation<'a> {
+    pub fn new(machine: &'a Machine, presses: Vec<usize>) -> Self {
+        assert_eq!(
+            presses.len(),
+            machine.buttons.len(),
+            "one count per button"
+        );
+        Self { machine, presses }
+    }
+
+    pub fn total(&self) -> usize {
+        self.presses.iter().sum()
+    }
+
+    /// Replays the presses on a machine with all lights off and panics
+    /// unless the indicator pattern comes out.
+    pub fn verify_lights(&self) -> &Self {
+        assert_eq!(
+            self.machine.press_lights(&self.presses),
+            self.machine.lights,
+            "{self} does not light up {}",
+            self.machine
+        );
+        self
+    }
+
+    /// Replays the presses on zeroed counters and panics unless they end up
+    /// at the joltage requirements.
+    pub fn verify_joltage(&self) -> &Self {
+        assert_eq!(
+            self.machine.press_joltage(&self.presses),
+            self.machine.joltages,
+            "{self} does not reach the joltages of {}",
+            self.machine
+        );
+        self
+    }
+}
+
+impl Display for Explanation<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let pressed = self
+            .presses
+            .iter()
+            .zip(&self.machine.buttons)
+            .filter(|(&count, _)| count > 0)
+            .map(|(count, button)| {
+                let wires =
+                    button.iter().map(|i| i.to_string()).collect::<Vec<_>>();
+                format!("({}) x{count}", wires.join(","))
+            })
+            .collect::<Vec<_>>();
+        write!(f, "{} presses: {}", self.total(), pressed.join(" "))
+    }
+}
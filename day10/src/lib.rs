@@ -0,0 +1,233 @@
+use common::gf2::{self, BitMatrix, BitRow};
+use common::ilp::{IlpError, IntegerProgram};
+use common::solution::Solution;
+use explain::Explanation;
+use machine::Machine;
+
+mod explain;
+mod machine;
+
+/// Reports every malformed line instead of stopping at the first one, and
+/// echoes the valid ones in canonical form.
+pub fn check(input: &str) {
+    let mut failed = false;
+    for (i, line) in input.lines().enumerate() {
+        match line.parse::<Machine>() {
+            Ok(machine) => println!("{machine}"),
+            Err(e) => {
+                failed = true;
+                eprintln!("line {}, {e}", i + 1);
+            }
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
+
+pub fn explain(input: &str) {
+    let machines = parse_machines(input).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        std::process::exit(1);
+    });
+    for machine in machines {
+        println!("{machine}");
+        match explain_lights(&machine) {
+            Ok(explanation) => {
+                println!("  lights:  {}", explanation.verify_lights())
+            }
+            Err(e) => println!("  lights:  {e}"),
+        }
+        match explain_joltage(&machine) {
+            Ok(explanation) => {
+                println!("  joltage: {}", explanation.verify_joltage())
+            }
+            Err(e) => println!("  joltage: {e}"),
+        }
+    }
+}
+
+fn parse_machines(input: &str) -> Result<Vec<Machine>, String> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse().map_err(|e| format!("line {}, {e}", i + 1))
+        })
+        .collect()
+}
+
+fn explain_lights(machine: &Machine) -> Result<Explanation<'_>, &str> {
+    let buttons = machine.buttons.len();
+    // one equation per light: the buttons wired to it must be pressed an
+    // odd number of times iff the light ends up on
+    let mut a = BitMatrix::new(buttons);
+    for light in 0..machine.lights.len() {
+        a.push_row(BitRow::from_ones(
+            buttons,
+            (0..buttons).filter(|&b| machine.buttons[b].contains(&light)),
+        ));
+    }
+    let target = BitRow::from_bits(machine.lights.iter().copied());
+
+    let pressed = gf2::solve(&a, &target)
+        .ok_or("lights cannot be reached")?
+        .min_weight();
+    Ok(Explanation::new(
+        machine,
+        pressed.iter_bits().map(usize::from).collect(),
+    ))
+}
+
+fn part1(machines: &[Machine]) -> Result<usize, String> {
+    machines
+        .iter()
+        .map(|machine| {
+            explain_lights(machine)
+                .map(|explanation| explanation.verify_lights().total())
+                .map_err(|e| format!("{machine}: {e}"))
+        })
+        .sum()
+}
+
+pub fn solve_buttons(
+    a: &[Vec<i32>],
+    b: &[i32],
+) -> Result<(i32, Vec<i32>), IlpError> {
+    let m = a.len();
+    let n = b.len();
+    // with nothing to press only an all-zero target is reachable
+    if m == 0 {
+        if b.iter().any(|&v| v != 0) {
+            return Err(IlpError::Infeasible);
+        }
+        return Ok((0, vec![]));
+    }
+
+    let mut program = IntegerProgram::new(m).minimise(vec![1; m]);
+
+    // A^T x = b
+    for j in 0..n {
+        let coeffs = (0..m).map(|i| a[i][j] as i64).collect();
+        program.add_equality(coeffs, b[j] as i64);
+    }
+
+    let sol = program.solve()?;
+    let x_val = sol.values.iter().map(|&v| v as i32).collect::<Vec<_>>();
+    Ok((sol.objective as i32, x_val))
+}
+
+fn explain_joltage(machine: &Machine) -> Result<Explanation<'_>, IlpError> {
+    let masks: Vec<Vec<i32>> = machine
+        .buttons
+        .iter()
+        .map(|vec| {
+            (0..machine.lights.len())
+                .map(|i| if vec.contains(&i) { 1 } else { 0 })
+                .collect()
+        })
+        .collect::<Vec<_>>();
+    let values = machine
+        .joltages
+        .iter()
+        .map(|&i| i as i32)
+        .collect::<Vec<_>>();
+    let (_, presses) = solve_buttons(&masks, &values)?;
+    Ok(Explanation::new(
+        machine,
+        presses.into_iter().map(|p| p as usize).collect(),
+    ))
+}
+
+fn part2(machines: &[Machine]) -> Result<usize, IlpError> {
+    machines
+        .iter()
+        .map(|machine| Ok(explain_joltage(machine)?.verify_joltage().total()))
+        .sum()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Parsed<'a> = Result<Vec<Machine>, String>;
+    type Answer1 = Result<usize, String>;
+    type Answer2 = Result<usize, String>;
+
+    const DAY: u8 = 10;
+
+    fn parse(input: &str) -> Result<Vec<Machine>, String> {
+        parse_machines(input)
+    }
+
+    fn part1(
+        machines: &Result<Vec<Machine>, String>,
+    ) -> Result<usize, String> {
+        machines
+            .as_ref()
+            .map_err(String::clone)
+            .and_then(|m| part1(m))
+    }
+
+    fn part2(
+        machines: &Result<Vec<Machine>, String>,
+    ) -> Result<usize, String> {
+        machines
+            .as_ref()
+            .map_err(String::clone)
+            .and_then(|m| part2(m).map_err(|e| e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+
+    #[test]
+    fn test_solution() {
+        let machines = parse_machines(&example(2025, 10, 1)).unwrap();
+        assert_eq!(part1(&machines), Ok(example_answer(2025, 10, 1)));
+        assert_eq!(part2(&machines), Ok(example_answer(2025, 10, 2)));
+    }
+
+    #[test]
+    fn test_explain() {
+        let machine = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"
+            .parse::<Machine>()
+            .unwrap();
+        let lights = explain_lights(&machine).unwrap();
+        assert_eq!(lights.verify_lights().total(), 2);
+        let joltage = explain_joltage(&machine).unwrap();
+        assert_eq!(joltage.verify_joltage().total(), 10);
+
+        let wrong = Explanation::new(&machine, vec![1, 0, 0, 0, 0, 0]);
+        assert_eq!(wrong.to_string(), "1 presses: (3) x1");
+        assert!(std::panic::catch_unwind(|| {
+            wrong.verify_lights();
+        })
+        .is_err());
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let parsed = Day10::parse("[.#] (1) {1,2}\n[.#] (0) {1,2,3}");
+        let error = "line 2, column 10: 3 joltage values given for 2 lights";
+        assert_eq!(Day10::part1(&parsed), Err(error.to_string()));
+        assert_eq!(Day10::part2(&parsed), Err(error.to_string()));
+    }
+
+    #[test]
+    fn test_unreachable_lights() {
+        let machines = parse_machines("[##] (0) {1,1}").unwrap();
+        assert_eq!(
+            part1(&machines),
+            Err("[##] (0) {1,1}: lights cannot be reached".to_string())
+        );
+    }
+
+    #[test]
+    fn test_no_buttons() {
+        assert_eq!(solve_buttons(&[], &[0, 0]), Ok((0, vec![])));
+        assert_eq!(solve_buttons(&[], &[0, 1]), Err(IlpError::Infeasible));
+    }
+}
@@ -0,0 +1,296 @@
+use nom::{
+    character::complete::{char as nom_char, digit1, one_of},
+    combinator::{cut, map_res},
+    multi::{many0, separated_list0},
+    sequence::{delimited, preceded},
+    IResult,
+};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// The raw pieces of a line, with the 1-based column of every number so
+/// validation can point at the offending one.
+#[derive(Debug, PartialEq)]
+pub struct Parsed {
+    pub mask: Vec<char>,
+    pub groups: Vec<Vec<usize>>,
+    pub values: Vec<usize>,
+    pub group_columns: Vec<Vec<usize>>,
+    pub values_column: usize,
+}
+
+fn parse_usize(input: &str) -> IResult<&str, usize> {
+    map_res(digit1, str::parse)(input)
+}
+
+/* the value together with the input length left before it */
+fn parse_located_usize(input: &str) -> IResult<&str, (usize, usize)> {
+    let (rest, value) = parse_usize(input)?;
+    Ok((rest, (input.len(), value)))
+}
+
+/* [.###.#] */
+fn parse_mask(input: &str) -> IResult<&str, Vec<char>> {
+    delimited(nom_char('['), many0(one_of(".#")), nom_char(']'))(input)
+}
+
+/* (0,1,2,3); once the bracket is open, errors point inside the group
+rather than backtracking to it */
+fn parse_group(input: &str) -> IResult<&str, Vec<(usize, usize)>> {
+    delimited(
+        nom_char('('),
+        cut(separated_list0(nom_char(','), parse_located_usize)),
+        cut(nom_char(')')),
+    )(input)
+}
+
+/* {10,11,11,5,10,5} */
+fn parse_values(input: &str) -> IResult<&str, Vec<usize>> {
+    delimited(
+        nom_char('{'),
+        cut(separated_list0(nom_char(','), parse_usize)),
+        cut(nom_char('}')),
+    )(input)
+}
+
+/* Full line */
+pub fn parse_line(line: &str) -> IResult<&str, Parsed> {
+    let column = |left: usize| line.len() - left + 1;
+    let (input, mask) = parse_mask(line)?;
+    let (input, groups) = many0(preceded(nom_char(' '), parse_group))(input)?;
+    let (input, _) = nom_char(' ')(input)?;
+    let values_column = column(input.len());
+    let (input, values) = parse_values(input)?;
+
+    Ok((
+        input,
+        Parsed {
+            mask,
+            group_columns: groups
+                .iter()
+                .map(|group| group.iter().map(|&(l, _)| column(l)).collect())
+                .collect(),
+            groups: groups
+                .into_iter()
+                .map(|group| group.into_iter().map(|(_, i)| i).collect())
+                .collect(),
+            values,
+            values_column,
+        },
+    ))
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MachineError {
+    Syntax {
+        column: usize,
+    },
+    LightOutOfRange {
+        column: usize,
+        button: usize,
+        light: usize,
+        lights: usize,
+    },
+    JoltageCount {
+        column: usize,
+        found: usize,
+        lights: usize,
+    },
+}
+
+impl Display for MachineError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MachineError::Syntax { column } => {
+                write!(f, "column {column}: unexpected input")
+            }
+            MachineError::LightOutOfRange {
+                column,
+                button,
+                light,
+                lights,
+            } => write!(
+                f,
+                "column {column}: button {button} toggles light {light} \
+                 but the machine has {lights} lights"
+            ),
+            MachineError::JoltageCount {
+                column,
+                found,
+                lights,
+            } => write!(
+                f,
+                "column {column}: {found} joltage values given for \
+                 {lights} lights"
+            ),
+        }
+    }
+}
+
+impl Error for MachineError {}
+
+/// A validated machine: every button only wires up existing lights and
+/// there is one joltage counter per light.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Machine {
+    pub lights: Vec<bool>,
+    pub buttons: Vec<Vec<usize>>,
+    pub joltages: Vec<usize>,
+}
+
+impl Machine {
+    /// The indicator lights after pressing each button the given number of
+    /// times, starting from all off.
+    pub fn press_lights(&self, presses: &[usize]) -> Vec<bool> {
+        let mut lights = vec![false; self.lights.len()];
+        for (button, &count) in self.buttons.iter().zip(presses) {
+            for &light in button {
+                lights[light] ^= count % 2 == 1;
+            }
+        }
+        lights
+    }
+
+    /// The joltage counters after pressing each button the given number of
+    /// times, starting from zero.
+    pub fn press_joltage(&self, presses: &[usize]) -> Vec<usize> {
+        let mut counters = vec![0; self.joltages.len()];
+        for (button, &count) in self.buttons.iter().zip(presses) {
+            for &counter in button {
+                counters[counter] += count;
+            }
+        }
+        counters
+    }
+}
+
+impl TryFrom<Parsed> for Machine {
+    type Error = MachineError;
+
+    fn try_from(parsed: Parsed) -> Result<Self, Self::Error> {
+        let lights = parsed.mask.len();
+        for (button, group) in parsed.groups.iter().enumerate() {
+            for (&light, &column) in
+                group.iter().zip(&parsed.group_columns[button])
+            {
+                if light >= lights {
+                    return Err(MachineError::LightOutOfRange {
+                        column,
+                        button,
+                        light,
+                        lights,
+                    });
+                }
+            }
+        }
+        if parsed.values.len() != lights {
+            return Err(MachineError::JoltageCount {
+                column: parsed.values_column,
+                found: parsed.values.len(),
+                lights,
+            });
+        }
+        Ok(Self {
+            lights: parsed.mask.iter().map(|&c| c == '#').collect(),
+            buttons: parsed.groups,
+            joltages: parsed.values,
+        })
+    }
+}
+
+impl FromStr for Machine {
+    type Err = MachineError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let column = |rest: &str| line.len() - rest.len() + 1;
+        let (rest, parsed) = parse_line(line).map_err(|e| match e {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                MachineError::Syntax {
+                    column: column(e.input),
+                }
+            }
+            nom::Err::Incomplete(_) => MachineError::Syntax {
+                column: line.len() + 1,
+            },
+        })?;
+        if !rest.is_empty() {
+            return Err(MachineError::Syntax {
+                column: column(rest),
+            });
+        }
+        Machine::try_from(parsed)
+    }
+}
+
+fn join(values: &[usize]) -> String {
+    values
+        .iter()
+        .map(|v| v.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+impl Display for Machine {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let mask: String = self
+            .lights
+            .iter()
+            .map(|&on| if on { '#' } else { '.' })
+            .collect();
+        write!(f, "[{mask}]")?;
+        for button in &self.buttons {
+            write!(f, " ({})", join(button))?;
+        }
+        write!(f, " {{{}}}", join(&self.joltages))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        for line in [
+            "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}",
+            "[...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}",
+        ] {
+            let machine = line.parse::<Machine>().unwrap();
+            assert_eq!(machine.to_string(), line);
+        }
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            "[.##.] (3) (1,4) {3,5,4,7}".parse::<Machine>(),
+            Err(MachineError::LightOutOfRange {
+                column: 15,
+                button: 1,
+                light: 4,
+                lights: 4,
+            })
+        );
+        assert_eq!(
+            "[.##.] (3) (1,3) {3,5,4}".parse::<Machine>(),
+            Err(MachineError::JoltageCount {
+                column: 18,
+                found: 3,
+                lights: 4,
+            })
+        );
+        assert_eq!(
+            "[.##.] (3) (1;3) {3,5,4,7}".parse::<Machine>(),
+            Err(MachineError::Syntax { column: 14 })
+        );
+        assert_eq!(
+            "[.##.] (3) (1,3) {3,5;4,7}".parse::<Machine>(),
+            Err(MachineError::Syntax { column: 22 })
+        );
+        assert_eq!(
+            "[.##.] (3) (1,3) {3,5,4,7} x".parse::<Machine>(),
+            Err(MachineError::Syntax { column: 27 })
+        );
+    }
+}
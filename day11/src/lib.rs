@@ -0,0 +1,103 @@
+use common::dot::Dot;
+use common::graph::{Graph, NoEdge, PathQuery};
+use common::solution::Solution;
+
+fn parse(input: &str) -> Result<Graph, NoEdge> {
+    Graph::parse(input, true)
+}
+
+/// The device graph with the endpoints and waypoints of both parts marked.
+pub fn dot(input: &str) -> Result<String, NoEdge> {
+    let graph = parse(input)?;
+    let marked = ["you", "svr", "dac", "fft", "out"]
+        .into_iter()
+        .filter_map(|name| graph.id(name));
+    Ok(Dot::new(&graph).highlight(marked).to_string())
+}
+
+/// Paths from `from` to `to` passing through every waypoint, in any order.
+fn count(
+    graph: &Graph,
+    from: &str,
+    to: &str,
+    waypoints: &[&str],
+) -> Result<u128, String> {
+    let id = |name: &str| {
+        graph
+            .id(name)
+            .ok_or_else(|| format!("no device named {name}"))
+    };
+    let query = PathQuery {
+        waypoints: waypoints
+            .iter()
+            .map(|w| id(w))
+            .collect::<Result<_, _>>()?,
+        ..PathQuery::default()
+    };
+    graph
+        .count_paths(id(from)?, id(to)?, &query)
+        .map_err(|cycle| graph.describe(&cycle))
+}
+
+fn part1(graph: &Graph) -> Result<u128, String> {
+    count(graph, "you", "out", &[])
+}
+
+fn part2(graph: &Graph) -> Result<u128, String> {
+    count(graph, "svr", "out", &["dac", "fft"])
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Parsed<'a> = Result<Graph, NoEdge>;
+    type Answer1 = Result<u128, String>;
+    type Answer2 = Result<u128, String>;
+
+    const DAY: u8 = 11;
+
+    fn parse(input: &str) -> Result<Graph, NoEdge> {
+        parse(input)
+    }
+
+    fn part1(graph: &Result<Graph, NoEdge>) -> Result<u128, String> {
+        graph.as_ref().map_err(|e| e.to_string()).and_then(part1)
+    }
+
+    fn part2(graph: &Result<Graph, NoEdge>) -> Result<u128, String> {
+        graph.as_ref().map_err(|e| e.to_string()).and_then(part2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+    use indoc::indoc;
+
+    #[test]
+    fn test_part1() {
+        let graph = parse(&example(2025, 11, 1)).unwrap();
+        assert_eq!(part1(&graph), Ok(example_answer(2025, 11, 1)));
+    }
+
+    #[test]
+    fn test_part1_cycle() {
+        let input = indoc! {
+            r#"
+            you: aaa
+            aaa: bbb out
+            bbb: aaa
+            "#
+        };
+        let graph = parse(input).unwrap();
+        assert_eq!(part1(&graph), Err("cycle aaa -> bbb -> aaa".to_string()));
+    }
+
+    #[test]
+    fn test_part2() {
+        let graph = parse(&example(2025, 11, 2)).unwrap();
+        assert_eq!(part2(&graph), Ok(example_answer(2025, 11, 2)));
+        assert_eq!(part1(&graph), Err("no device named you".to_string()));
+    }
+}
@@ -0,0 +1,201 @@
+use common::polyomino::Polyomino;
+use common::solution::Solution;
+use itertools::Itertools;
+use packing::{pack, Piece};
+use render::Render;
+
+mod packing;
+mod render;
+
+type Region = (usize, usize, Vec<usize>);
+
+/// The pieces in the order they are listed and the regions to fill.
+type Farm = (Vec<Piece>, Vec<Region>);
+
+fn parse(input: &str) -> Result<Farm, String> {
+    let (matrices_str, regions_str) = input
+        .rsplit_once("\n\n")
+        .ok_or("no blank line between the shapes and the regions")?;
+    let pieces = matrices_str
+        .split("\n\n")
+        .map(|block| {
+            let (label, rows) = block.split_once('\n').unwrap_or((block, ""));
+            rows.parse::<Polyomino>()
+                .and_then(|shape| Piece::new(&shape))
+                .map_err(|e| format!("shape {label} {e}"))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let regions = regions_str
+        .lines()
+        .map(|line| {
+            let malformed = || format!("region {line:?} is not WxH: counts");
+            let (region, constraint) =
+                line.split_once(": ").ok_or_else(malformed)?;
+            let (width, height) =
+                region.split_once('x').ok_or_else(malformed)?;
+            let to_places = constraint
+                .split_ascii_whitespace()
+                .map(str::parse)
+                .collect::<Result<Vec<usize>, _>>()
+                .map_err(|_| malformed())?;
+            if to_places.len() != pieces.len() {
+                return Err(format!(
+                    "region {line:?} gives {} counts for {} shapes",
+                    to_places.len(),
+                    pieces.len()
+                ));
+            }
+            Ok((
+                width.parse().map_err(|_| malformed())?,
+                height.parse().map_err(|_| malformed())?,
+                to_places,
+            ))
+        })
+        .collect::<Result<_, String>>()?;
+    Ok((pieces, regions))
+}
+
+fn part1((pieces, regions): &Farm) -> Result<usize, String> {
+    regions
+        .iter()
+        .map(|(width, height, to_places)| {
+            pack(*width, *height, pieces, to_places)
+                .map(|placements| usize::from(placements.is_some()))
+        })
+        .sum()
+}
+
+/// Every region followed by the arrangement found for it.
+pub fn render(input: &str, colour: bool) -> Result<String, String> {
+    let (pieces, regions) = parse(input)?;
+    let mut text = String::new();
+    for (width, height, to_places) in &regions {
+        text += &format!("{width}x{height}: {}\n", to_places.iter().join(" "));
+        text += &match pack(*width, *height, &pieces, to_places)? {
+            Some(placements) => {
+                Render::new(*width, *height, &pieces, &placements)
+                    .coloured(colour)
+                    .to_string()
+            }
+            None => "does not fit\n".to_string(),
+        };
+        text += "\n";
+    }
+    Ok(text)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Parsed<'a> = Result<Farm, String>;
+    type Answer1 = Result<usize, String>;
+    type Answer2 = &'static str;
+
+    const DAY: u8 = 12;
+    const PARTS: u8 = 1;
+
+    fn parse(input: &str) -> Result<Farm, String> {
+        parse(input)
+    }
+
+    fn part1(farm: &Result<Farm, String>) -> Result<usize, String> {
+        farm.as_ref().map_err(String::clone).and_then(part1)
+    }
+
+    fn part2(_: &Result<Farm, String>) -> &'static str {
+        unreachable!("the last day has a single puzzle")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+    use indoc::indoc;
+
+    #[test]
+    fn test_part1() {
+        let input = &example(2025, 12, 1);
+        assert_eq!(
+            part1(&parse(input).unwrap()),
+            Ok(example_answer(2025, 12, 1))
+        );
+    }
+
+    #[test]
+    fn test_non_square_shapes() {
+        let input = indoc! {
+            r#"
+            0:
+            ####
+
+            1:
+            ##
+            #.
+
+            4x2: 1 0
+            4x1: 1 0
+            3x3: 1 0
+            2x2: 0 1
+            "#
+        };
+        assert_eq!(part1(&parse(input).unwrap()), Ok(3));
+        assert_eq!(
+            render(input, false).unwrap(),
+            indoc! {"
+                4x2: 1 0
+                AAAA
+                ....
+
+                4x1: 1 0
+                AAAA
+
+                3x3: 1 0
+                does not fit
+
+                2x2: 0 1
+                AA
+                A.
+
+            "}
+        );
+    }
+
+    #[test]
+    fn test_empty_shape() {
+        let input = "0:\n###\n\n1:\n...\n\n3x3: 1 1\n";
+        assert_eq!(
+            parse(input).err(),
+            Some("shape 1: shape has no cells".into())
+        );
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        for (input, error) in [
+            (
+                "0:\n###\n",
+                "no blank line between the shapes and the regions",
+            ),
+            ("0:\n###\n\n3x3 1\n", r#"region "3x3 1" is not WxH: counts"#),
+            ("0:\n###\n\n3: 1\n", r#"region "3: 1" is not WxH: counts"#),
+            (
+                "0:\n###\n\n3x3: a\n",
+                r#"region "3x3: a" is not WxH: counts"#,
+            ),
+            (
+                "0:\n###\n\n3x3: 1 2\n",
+                r#"region "3x3: 1 2" gives 2 counts for 1 shapes"#,
+            ),
+        ] {
+            assert_eq!(parse(input).err(), Some(error.to_string()), "{input}");
+        }
+
+        let input = "0:\n###\n\n129x1: 1\n";
+        assert_eq!(
+            Day12::part1(&Day12::parse(input)),
+            Err("regions are limited to 128 columns, not 129".to_string())
+        );
+    }
+}
@@ -0,0 +1,377 @@
+use common::polyomino::{Polyomino, PolyominoError};
+
+/// One orientation of a piece as bit rows relative to its bounding box,
+/// plus the column of its first cell in the top row: the cell that lands
+/// on the first empty cell of the board when it is placed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Orientation {
+    rows: Vec<u128>,
+    anchor: usize,
+    width: usize,
+    cells: Vec<(usize, usize)>,
+}
+
+impl Orientation {
+    fn new(cells: Vec<(usize, usize)>) -> Self {
+        let height = cells.iter().map(|&(r, _)| r + 1).max().unwrap_or(0);
+        let width = cells.iter().map(|&(_, c)| c + 1).max().unwrap_or(0);
+        let mut rows = vec![0u128; height];
+        for &(r, c) in &cells {
+            rows[r] |= 1 << c;
+        }
+        let anchor = rows[0].trailing_zeros() as usize;
+        Self {
+            rows,
+            anchor,
+            width,
+            cells,
+        }
+    }
+
+    fn height(&self) -> usize {
+        self.rows.len()
+    }
+}
+
+/// A piece type with every distinct orientation it may be placed in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Piece {
+    orientations: Vec<Orientation>,
+}
+
+impl Piece {
+    /// A shape without cells has no anchor to place it by, so it is
+    /// refused here rather than left to the search.
+    pub fn new(shape: &Polyomino) -> Result<Self, PolyominoError> {
+        if shape.cells().is_empty() {
+            return Err(PolyominoError::Empty);
+        }
+        Ok(Self {
+            orientations: shape
+                .orientations()
+                .iter()
+                .map(|o| Orientation::new(o.cells().to_vec()))
+                .collect(),
+        })
+    }
+
+    pub fn area(&self) -> usize {
+        self.orientations[0].cells.len()
+    }
+
+    pub fn cells(&self, orientation: usize) -> &[(usize, usize)] {
+        &self.orientations[orientation].cells
+    }
+}
+
+/// A piece put on the board with its bounding box at `(row, col)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Placement {
+    pub piece: usize,
+    pub orientation: usize,
+    pub row: usize,
+    pub col: usize,
+}
+
+struct Search<'a> {
+    width: usize,
+    height: usize,
+    pieces: &'a [Piece],
+    counts: Vec<usize>,
+    remaining: usize,
+    board: Vec<u128>,
+    // cells that may still be left uncovered
+    slack: usize,
+    placed: Vec<Placement>,
+}
+
+impl Search<'_> {
+    fn fits(&self, orientation: &Orientation, row: usize, col: usize) -> bool {
+        orientation
+            .rows
+            .iter()
+            .zip(&self.board[row..])
+            .all(|(&bits, &board)| bits << col & board == 0)
+    }
+
+    fn toggle(&mut self, orientation: &Orientation, row: usize, col: usize) {
+        for (bits, board) in
+            orientation.rows.iter().zip(&mut self.board[row..])
+        {
+            *board ^= bits << col;
+        }
+    }
+
+    fn dfs(&mut self, mut cell: usize) -> bool {
+        if self.remaining == 0 {
+            return true;
+        }
+        let cells = self.width * self.height;
+        while cell < cells
+            && self.board[cell / self.width] >> (cell % self.width) & 1 == 1
+        {
+            cell += 1;
+        }
+        if cell == cells {
+            return false;
+        }
+        let (row, col) = (cell / self.width, cell % self.width);
+
+        // identical pieces are only ever chosen by type, so no two
+        // arrangements differing by a swap of equal pieces are explored
+        let pieces = self.pieces;
+        for (piece, kind) in pieces.iter().enumerate() {
+            if self.counts[piece] == 0 {
+                continue;
+            }
+            for (o, orientation) in kind.orientations.iter().enumerate() {
+                let Some(left) = col.checked_sub(orientation.anchor) else {
+                    continue;
+                };
+                if left + orientation.width > self.width
+                    || row + orientation.height() > self.height
+                    || !self.fits(orientation, row, left)
+                {
+                    continue;
+                }
+                self.toggle(orientation, row, left);
+                self.counts[piece] -= 1;
+                self.remaining -= 1;
+                self.placed.push(Placement {
+                    piece,
+                    orientation: o,
+                    row,
+                    col: left,
+                });
+                if self.dfs(cell + 1) {
+                    return true;
+                }
+                self.placed.pop();
+                self.remaining += 1;
+                self.counts[piece] += 1;
+                self.toggle(orientation, row, left);
+            }
+        }
+
+        // or leave this cell empty for good
+        if self.slack > 0 {
+            self.slack -= 1;
+            self.board[row] |= 1 << col;
+            if self.dfs(cell + 1) {
+                return true;
+            }
+            self.board[row] &= !(1 << col);
+            self.slack += 1;
+        }
+        false
+    }
+}
+
+/// Gives every piece its own bounding box in a grid of equal slots, which
+/// settles the loose regions without any search.
+fn pack_in_slots(
+    width: usize,
+    height: usize,
+    pieces: &[Piece],
+    counts: &[usize],
+) -> Option<Vec<Placement>> {
+    let slot_w = pieces.iter().map(|p| p.orientations[0].width).max()?;
+    let slot_h = pieces.iter().map(|p| p.orientations[0].height()).max()?;
+    let per_row = width / slot_w;
+    let slots = per_row * (height / slot_h);
+    if counts.iter().sum::<usize>() > slots {
+        return None;
+    }
+    let placements = counts
+        .iter()
+        .enumerate()
+        .flat_map(|(piece, &count)| std::iter::repeat_n(piece, count))
+        .enumerate()
+        .map(|(slot, piece)| Placement {
+            piece,
+            orientation: 0,
+            row: slot / per_row * slot_h,
+            col: slot % per_row * slot_w,
+        })
+        .collect();
+    Some(placements)
+}
+
+/// Places `counts[i]` copies of every piece `i` in a `width` x `height`
+/// region without overlap, or proves that no arrangement exists. Regions
+/// wider than 128 columns do not fit the bit rows and are an error.
+///
+/// Cells are filled in reading order: the first empty cell is either
+/// covered by some piece or declared empty, which is only allowed while
+/// the region has more area than the pieces need.
+pub fn pack(
+    width: usize,
+    height: usize,
+    pieces: &[Piece],
+    counts: &[usize],
+) -> Result<Option<Vec<Placement>>, String> {
+    assert_eq!(pieces.len(), counts.len(), "one count per piece");
+    if width > 128 {
+        return Err(format!(
+            "regions are limited to 128 columns, not {width}"
+        ));
+    }
+    let area = pieces
+        .iter()
+        .zip(counts)
+        .map(|(piece, &count)| piece.area() * count)
+        .sum::<usize>();
+    if area > width * height {
+        return Ok(None);
+    }
+    if let Some(placements) = pack_in_slots(width, height, pieces, counts) {
+        return Ok(Some(placements));
+    }
+
+    let mut search = Search {
+        width,
+        height,
+        pieces,
+        counts: counts.to_vec(),
+        remaining: counts.iter().sum(),
+        board: vec![0; height],
+        slack: width * height - area,
+        placed: vec![],
+    };
+    Ok(search.dfs(0).then_some(search.placed))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::dlx::Dlx;
+    use common::gf2::BitRow;
+
+    fn l_tromino() -> Piece {
+        Piece::new(&"#.\n##".parse().unwrap()).unwrap()
+    }
+
+    /// Every position of every orientation as a bitvector over the cells.
+    fn placement_rows(
+        width: usize,
+        height: usize,
+        piece: &Piece,
+    ) -> Vec<BitRow> {
+        let mut rows = vec![];
+        let fitting = piece.orientations.iter().filter(|orientation| {
+            orientation.width <= width && orientation.height() <= height
+        });
+        for orientation in fitting {
+            for row in 0..=height - orientation.height() {
+                for col in 0..=width - orientation.width {
+                    let cells = orientation
+                        .cells
+                        .iter()
+                        .map(|&(r, c)| (row + r) * width + col + c);
+                    rows.push(BitRow::from_ones(width * height, cells));
+                }
+            }
+        }
+        rows
+    }
+
+    /// The same question as `pack` posed as exact cover: one primary column
+    /// per piece copy and one secondary column per cell. Copies of a piece
+    /// are labelled, so each arrangement is found once per permutation of
+    /// them.
+    fn dlx(
+        width: usize,
+        height: usize,
+        pieces: &[Piece],
+        counts: &[usize],
+    ) -> Dlx {
+        let copies = counts.iter().sum::<usize>();
+        let mut dlx = Dlx::new(copies, width * height);
+        let kinds = counts
+            .iter()
+            .enumerate()
+            .flat_map(|(piece, &count)| std::iter::repeat_n(piece, count));
+        for (copy, piece) in kinds.enumerate() {
+            for bits in placement_rows(width, height, &pieces[piece]) {
+                dlx.add_row(
+                    std::iter::once(copy)
+                        .chain(bits.iter_ones().map(|cell| copies + cell)),
+                );
+            }
+        }
+        dlx
+    }
+
+    fn dlx_fits(
+        width: usize,
+        height: usize,
+        pieces: &[Piece],
+        counts: &[usize],
+    ) -> bool {
+        dlx(width, height, pieces, counts).first().is_some()
+    }
+
+    /// Arrangements with identical pieces not told apart: the labelled
+    /// count divided by the orderings of each piece's copies.
+    fn dlx_arrangements(
+        width: usize,
+        height: usize,
+        pieces: &[Piece],
+        counts: &[usize],
+    ) -> usize {
+        let labellings = counts
+            .iter()
+            .map(|&c| (1..=c).product::<usize>())
+            .product::<usize>();
+        dlx(width, height, pieces, counts).count() / labellings
+    }
+
+    #[test]
+    fn test_pack_matches_dlx() {
+        let domino = Piece::new(&"##".parse().unwrap()).unwrap();
+        let pieces = [l_tromino(), domino];
+        for (width, height) in [(3, 3), (4, 3), (5, 2), (4, 4)] {
+            for counts in [[0, 4], [2, 1], [3, 0], [1, 3], [4, 1], [2, 3]] {
+                assert_eq!(
+                    pack(width, height, &pieces, &counts).unwrap().is_some(),
+                    dlx_fits(width, height, &pieces, &counts),
+                    "{width}x{height} {counts:?}"
+                );
+            }
+        }
+
+        // a 2x2 square takes two dominoes side by side or stacked, and a
+        // 2x3 rectangle three dominoes in three ways
+        let dominoes = &pieces[1..];
+        assert_eq!(dlx(2, 2, dominoes, &[2]).count(), 4);
+        assert_eq!(dlx_arrangements(2, 2, dominoes, &[2]), 2);
+        assert_eq!(dlx(3, 2, dominoes, &[3]).count(), 18);
+        assert_eq!(dlx_arrangements(3, 2, dominoes, &[3]), 3);
+    }
+
+    #[test]
+    fn test_pack() {
+        let pieces = [l_tromino()];
+
+        let placements = pack(3, 2, &pieces, &[2]).unwrap().unwrap();
+        let mut covered = vec![];
+        for p in &placements {
+            for &(r, c) in pieces[p.piece].cells(p.orientation) {
+                covered.push((p.row + r, p.col + c));
+            }
+        }
+        covered.sort();
+        covered.dedup();
+        assert_eq!(covered.len(), 6);
+
+        // 3x3 famously cannot be tiled by L trominoes
+        assert_eq!(pack(3, 3, &pieces, &[3]), Ok(None));
+        assert!(pack(3, 3, &pieces, &[2]).unwrap().is_some());
+        assert_eq!(pack(5, 1, &pieces, &[1]), Ok(None));
+        assert_eq!(pack(3, 3, &pieces, &[4]), Ok(None));
+        assert!(pack(129, 1, &pieces, &[0]).is_err());
+
+        let empty = Polyomino::new([]);
+        assert_eq!(Piece::new(&empty), Err(PolyominoError::Empty));
+    }
+}
@@ -0,0 +1,111 @@
+use crate::packing::{Piece, Placement};
+use std::fmt::{Display, Formatter};
+
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+
+/// Draws an arrangement with every placed present as its own letter,
+/// reusing letters once there are more presents than the alphabet has.
+pub struct Render<'a> {
+    width: usize,
+    height: usize,
+    pieces: &'a [Piece],
+    placements: &'a [Placement],
+    colour: bool,
+}
+
+impl<'a> Render<'a> {
+    pub fn new(
+        width: usize,
+        height: usize,
+        pieces: &'a [Piece],
+        placements: &'a [Placement],
+    ) -> Self {
+        Self {
+            width,
+            height,
+            pieces,
+            placements,
+            colour: false,
+        }
+    }
+
+    /// Paints each present with one of the six ANSI foreground colours,
+    /// picked by piece type.
+    pub fn coloured(mut self, colour: bool) -> Self {
+        self.colour = colour;
+        self
+    }
+
+    /// The index of the placement covering each cell. Panics when two
+    /// presents overlap or one sticks out of the region.
+    fn grid(&self) -> Vec<Vec<Option<usize>>> {
+        let mut grid = vec![vec![None; self.width]; self.height];
+        for (i, p) in self.placements.iter().enumerate() {
+            for &(r, c) in self.pieces[p.piece].cells(p.orientation) {
+                let (r, c) = (p.row + r, p.col + c);
+                let cell = &mut grid[r][c];
+                assert!(cell.is_none(), "presents overlap at {:?}", (r, c));
+                *cell = Some(i);
+            }
+        }
+        grid
+    }
+}
+
+impl Display for Render<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for row in self.grid() {
+            for cell in row {
+                let Some(i) = cell else {
+                    write!(f, ".")?;
+                    continue;
+                };
+                let letter = LETTERS[i % LETTERS.len()] as char;
+                if self.colour {
+                    let code = 31 + self.placements[i].piece % 6;
+                    write!(f, "\x1b[{code}m{letter}\x1b[0m")?;
+                } else {
+                    write!(f, "{letter}")?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use indoc::indoc;
+
+    #[test]
+    fn test_render() {
+        let pieces = [Piece::new(&"#.\n##".parse().unwrap()).unwrap()];
+        // the L as drawn, then turned half way round next to it
+        let placements = [
+            Placement {
+                piece: 0,
+                orientation: 2,
+                row: 0,
+                col: 0,
+            },
+            Placement {
+                piece: 0,
+                orientation: 1,
+                row: 0,
+                col: 1,
+            },
+        ];
+        let render = Render::new(4, 2, &pieces, &placements);
+        assert_eq!(
+            render.to_string(),
+            indoc! {"
+                ABB.
+                AAB.
+            "}
+        );
+        let coloured = render.coloured(true).to_string();
+        assert!(coloured.starts_with("\x1b[31mA\x1b[0m\x1b[31mB"));
+    }
+}
@@ -0,0 +1,110 @@
+use common::solution::Solution;
+use itertools::Itertools;
+
+type Range = (usize, usize);
+
+fn parse(input: &str) -> Vec<Range> {
+    input
+        .trim()
+        .split(',')
+        .map(|range| range.split('-').collect_tuple().unwrap())
+        .map(|(a, b)| {
+            (a.parse::<usize>().unwrap(), b.parse::<usize>().unwrap())
+        })
+        .collect()
+}
+
+fn part1(ranges: &[Range]) -> usize {
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            (start..=end)
+                .map(|n| {
+                    let n_str = n.to_string();
+                    let (left, right) = n_str.split_at(n_str.len() / 2);
+                    if left == right {
+                        return n;
+                    }
+                    0
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+fn is_invalid(n: usize) -> bool {
+    let n_str = n.to_string();
+    for i in 1..=n_str.len() / 2 {
+        if !n_str.len().is_multiple_of(i) {
+            continue;
+        }
+        let same = n_str
+            .chars()
+            .collect::<Vec<char>>()
+            .chunks(i)
+            .map(|chunk| chunk.iter().collect::<String>())
+            .collect::<Vec<String>>()
+            .windows(2)
+            .all(|window| window[0] == window[1]);
+        if same {
+            return true;
+        }
+    }
+    false
+}
+
+fn part2(ranges: &[Range]) -> usize {
+    ranges
+        .iter()
+        .map(|&(start, end)| {
+            (start..=end)
+                .map(|n| {
+                    if is_invalid(n) {
+                        return n;
+                    }
+                    0
+                })
+                .sum::<usize>()
+        })
+        .sum()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Parsed<'a> = Vec<Range>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const DAY: u8 = 2;
+
+    fn parse(input: &str) -> Vec<Range> {
+        parse(input)
+    }
+
+    fn part1(ranges: &Vec<Range>) -> usize {
+        part1(ranges)
+    }
+
+    fn part2(ranges: &Vec<Range>) -> usize {
+        part2(ranges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+
+    #[test]
+    fn test_part1() {
+        let input = &example(2025, 2, 1);
+        assert_eq!(part1(&parse(input)), example_answer(2025, 2, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = &example(2025, 2, 1);
+        assert_eq!(part2(&parse(input)), example_answer(2025, 2, 2));
+    }
+}
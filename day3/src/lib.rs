@@ -0,0 +1,103 @@
+use common::solution::Solution;
+use std::collections::HashMap;
+
+/// The digits of every bank.
+fn parse(input: &str) -> Vec<Vec<usize>> {
+    input
+        .lines()
+        .map(|line| {
+            line.chars()
+                .map(|c| c.to_digit(10).unwrap() as usize)
+                .collect()
+        })
+        .collect()
+}
+
+fn part1(banks: &[Vec<usize>]) -> usize {
+    banks
+        .iter()
+        .map(|digits| {
+            let mut max = usize::MIN;
+            for left in 0..digits.len() {
+                for right in left + 1..digits.len() {
+                    if digits[left] * 10 + digits[right] > max {
+                        max = digits[left] * 10 + digits[right];
+                    }
+                }
+            }
+            max
+        })
+        .sum()
+}
+
+/// `digits` is always a suffix of one bank, so its length is enough to
+/// key the memo, which lives as long as the search of that bank.
+fn search(
+    digits: &[usize],
+    length: usize,
+    memo: &mut HashMap<(usize, usize), usize>,
+) -> usize {
+    if length == 1 {
+        return *digits.iter().max().unwrap();
+    }
+    if let Some(&max) = memo.get(&(digits.len(), length)) {
+        return max;
+    }
+    let right = digits.len() - length;
+    let mut max = usize::MIN;
+    for left in 0..=right {
+        max = max.max(
+            digits[left] * 10usize.pow((length - 1) as u32)
+                + search(&digits[left + 1..], length - 1, memo),
+        );
+    }
+    memo.insert((digits.len(), length), max);
+    max
+}
+
+fn part2(banks: &[Vec<usize>]) -> usize {
+    banks
+        .iter()
+        .map(|digits| search(digits, 12, &mut HashMap::new()))
+        .sum()
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Parsed<'a> = Vec<Vec<usize>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const DAY: u8 = 3;
+
+    fn parse(input: &str) -> Vec<Vec<usize>> {
+        parse(input)
+    }
+
+    fn part1(banks: &Vec<Vec<usize>>) -> usize {
+        part1(banks)
+    }
+
+    fn part2(banks: &Vec<Vec<usize>>) -> usize {
+        part2(banks)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+
+    #[test]
+    fn test_part1() {
+        let input = &example(2025, 3, 1);
+        assert_eq!(part1(&parse(input)), example_answer(2025, 3, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = &example(2025, 3, 1);
+        assert_eq!(part2(&parse(input)), example_answer(2025, 3, 2));
+    }
+}
@@ -0,0 +1,109 @@
+use common::solution::Solution;
+use common::Matrix;
+
+fn parse(input: &str) -> Matrix<char> {
+    Matrix::from(input, |c| c)
+}
+
+fn part1(matrix: &Matrix<char>) -> usize {
+    let mut total = 0;
+    for i in 0..matrix.height {
+        for j in 0..matrix.width {
+            if matrix[(i, j)] != '@' {
+                continue;
+            }
+            let num: usize = matrix
+                .get_adjacent_neighbours((i, j))
+                .iter()
+                .map(|coord| matrix[*coord])
+                .filter(|c| *c == '@')
+                .count();
+            if num < 4 {
+                total += 1;
+            }
+        }
+    }
+    total
+}
+
+fn transit(matrix: &Matrix<char>) -> Matrix<char> {
+    let mut next_matrix = matrix.clone();
+    for i in 0..matrix.height {
+        for j in 0..matrix.width {
+            if matrix[(i, j)] != '@' {
+                continue;
+            }
+            let num: usize = matrix
+                .get_adjacent_neighbours((i, j))
+                .iter()
+                .map(|coord| matrix[*coord])
+                .filter(|c| *c == '@')
+                .count();
+            if num < 4 {
+                next_matrix[(i, j)] = '.';
+            }
+        }
+    }
+    next_matrix
+}
+
+fn part2(init_matrix: &Matrix<char>) -> usize {
+    let mut matrix: Matrix<char> = init_matrix.clone();
+    let mut next_matrix: Matrix<char> = transit(&matrix);
+    while matrix != next_matrix {
+        matrix = next_matrix;
+        next_matrix = transit(&matrix);
+    }
+    let (mut original, mut now) = (0, 0);
+    for i in 0..matrix.height {
+        for j in 0..matrix.width {
+            if init_matrix[(i, j)] == '@' {
+                original += 1;
+            }
+            if next_matrix[(i, j)] == '@' {
+                now += 1;
+            }
+        }
+    }
+    original - now
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Parsed<'a> = Matrix<char>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const DAY: u8 = 4;
+
+    fn parse(input: &str) -> Matrix<char> {
+        parse(input)
+    }
+
+    fn part1(matrix: &Matrix<char>) -> usize {
+        part1(matrix)
+    }
+
+    fn part2(matrix: &Matrix<char>) -> usize {
+        part2(matrix)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+
+    #[test]
+    fn test_part1() {
+        let input = &example(2025, 4, 1);
+        assert_eq!(part1(&parse(input)), example_answer(2025, 4, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = &example(2025, 4, 1);
+        assert_eq!(part2(&parse(input)), example_answer(2025, 4, 2));
+    }
+}
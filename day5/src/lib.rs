@@ -0,0 +1,101 @@
+use common::solution::Solution;
+use itertools::Itertools;
+
+/// The fresh ranges merged into disjoint ones in increasing order, and
+/// the available ingredients.
+#[derive(Debug)]
+pub struct Inventory {
+    fresh: Vec<(usize, usize)>,
+    ingredients: Vec<usize>,
+}
+
+fn parse(input: &str) -> Inventory {
+    let (ranges, ingredients) = input.split_once("\n\n").unwrap();
+    let freshness = ranges
+        .lines()
+        .map(|line| line.split('-').collect_tuple().unwrap())
+        .map(|(start, end)| {
+            (
+                start.parse::<usize>().unwrap(),
+                end.parse::<usize>().unwrap(),
+            )
+        })
+        .sorted_by(|a, b| a.0.cmp(&b.0))
+        .collect::<Vec<(usize, usize)>>();
+
+    let mut merged = vec![freshness[0]];
+
+    for (current_start, current_end) in freshness[1..].iter() {
+        let (last_start, last_end) = merged.last().unwrap();
+
+        if current_start <= last_end {
+            let len = merged.len();
+            merged[len - 1] = (*last_start, *current_end.max(last_end));
+        } else {
+            merged.push((*current_start, *current_end))
+        }
+    }
+
+    Inventory {
+        fresh: merged,
+        ingredients: ingredients
+            .lines()
+            .map(|ingredient| ingredient.parse::<usize>().unwrap())
+            .collect(),
+    }
+}
+
+fn part1(inventory: &Inventory) -> usize {
+    let mut total = 0;
+    for &ingredient in &inventory.ingredients {
+        for (start, end) in inventory.fresh.iter() {
+            if ingredient >= *start && ingredient <= *end {
+                total += 1;
+                break;
+            }
+        }
+    }
+    total
+}
+
+fn part2(inventory: &Inventory) -> usize {
+    inventory
+        .fresh
+        .iter()
+        .fold(0, |acc, range| acc + range.1 - range.0 + 1)
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Parsed<'a> = Inventory;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const DAY: u8 = 5;
+
+    fn parse(input: &str) -> Inventory {
+        parse(input)
+    }
+
+    fn part1(inventory: &Inventory) -> usize {
+        part1(inventory)
+    }
+
+    fn part2(inventory: &Inventory) -> usize {
+        part2(inventory)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+
+    #[test]
+    fn test_solution() {
+        let inventory = parse(&example(2025, 5, 1));
+        assert_eq!(part1(&inventory), example_answer(2025, 5, 1));
+        assert_eq!(part2(&inventory), example_answer(2025, 5, 2));
+    }
+}
@@ -0,0 +1,120 @@
+use common::solution::Solution;
+
+/// One problem of the worksheet: the operator under it and its block of
+/// digits, a row of characters per line, all as wide as the block.
+#[derive(Debug)]
+pub struct Problem {
+    op: char,
+    rows: Vec<Vec<char>>,
+}
+
+impl Problem {
+    fn apply(&self, numbers: impl Iterator<Item = usize>) -> usize {
+        match self.op {
+            '+' => numbers.sum(),
+            '*' => numbers.product(),
+            op => panic!("unexpected operator {op:?}"),
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.rows.first().map_or(0, Vec::len)
+    }
+}
+
+fn number(digits: impl Iterator<Item = char>) -> usize {
+    digits.collect::<String>().trim().parse().unwrap()
+}
+
+/// Splits the worksheet into problems at the columns that are blank on
+/// every line, keeping the alignment both parts read differently.
+fn parse(input: &str) -> Vec<Problem> {
+    let mut lines: Vec<Vec<char>> =
+        input.lines().map(|line| line.chars().collect()).collect();
+    let signs = lines.pop().unwrap();
+    let width = lines.iter().chain([&signs]).map(Vec::len).max().unwrap();
+    let cell = |line: &Vec<char>, j: usize| *line.get(j).unwrap_or(&' ');
+    let blank =
+        |j: usize| lines.iter().chain([&signs]).all(|l| cell(l, j) == ' ');
+
+    let mut problems = vec![];
+    let mut start = 0;
+    for j in 0..=width {
+        if j < width && !blank(j) {
+            continue;
+        }
+        if j > start {
+            let op = (start..j)
+                .map(|k| cell(&signs, k))
+                .find(|&c| c != ' ')
+                .expect("problem without an operator");
+            let rows = lines
+                .iter()
+                .map(|line| (start..j).map(|k| cell(line, k)).collect())
+                .collect();
+            problems.push(Problem { op, rows });
+        }
+        start = j + 1;
+    }
+    problems
+}
+
+/// Numbers are written along the rows.
+fn part1(problems: &[Problem]) -> usize {
+    problems
+        .iter()
+        .map(|p| p.apply(p.rows.iter().map(|row| number(row.iter().copied()))))
+        .sum()
+}
+
+/// Numbers are written down the columns.
+fn part2(problems: &[Problem]) -> usize {
+    problems
+        .iter()
+        .map(|p| {
+            p.apply(
+                (0..p.width()).map(|j| number(p.rows.iter().map(|r| r[j]))),
+            )
+        })
+        .sum()
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Parsed<'a> = Vec<Problem>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const DAY: u8 = 6;
+
+    fn parse(input: &str) -> Vec<Problem> {
+        parse(input)
+    }
+
+    fn part1(problems: &Vec<Problem>) -> usize {
+        part1(problems)
+    }
+
+    fn part2(problems: &Vec<Problem>) -> usize {
+        part2(problems)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+
+    #[test]
+    fn test_part1() {
+        let input = &example(2025, 6, 1);
+        assert_eq!(part1(&parse(input)), example_answer(2025, 6, 1));
+    }
+
+    #[test]
+    fn test_part2() {
+        let input = &example(2025, 6, 1);
+        assert_eq!(part2(&parse(input)), example_answer(2025, 6, 2));
+    }
+}
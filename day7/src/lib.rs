@@ -0,0 +1,111 @@
+use common::solution::Solution;
+use common::{Coord, Inbound, Matrix};
+use std::collections::{HashMap, HashSet};
+
+fn dfs(
+    pos: Coord,
+    visited: &mut HashSet<Coord>,
+    matrix: &Matrix<char>,
+) -> usize {
+    let next_pos = (pos.0 + 1, pos.1);
+    if visited.contains(&next_pos) || !next_pos.inbound(matrix) {
+        return 0;
+    }
+    match matrix[next_pos] {
+        '.' => {
+            visited.insert(next_pos);
+            dfs(next_pos, visited, matrix)
+        }
+        '^' => {
+            let mut total = 1;
+            for pos in [(pos.0 + 1, pos.1 - 1), (pos.0 + 1, pos.1 + 1)] {
+                visited.insert(pos);
+                total += dfs(pos, visited, matrix);
+            }
+            total
+        }
+        _ => panic!("disco!"),
+    }
+}
+
+/// The memo is keyed by position alone, so it only holds for one grid.
+fn dfsv2(
+    pos: Coord,
+    matrix: &Matrix<char>,
+    memo: &mut HashMap<Coord, usize>,
+) -> usize {
+    let next_pos = (pos.0 + 1, pos.1);
+    if next_pos.0 == matrix.height && next_pos.1 < matrix.width {
+        return 1;
+    }
+    if let Some(&total) = memo.get(&pos) {
+        return total;
+    }
+    let total = match matrix[next_pos] {
+        '.' => dfsv2(next_pos, matrix, memo),
+        '^' => [(pos.0 + 1, pos.1 - 1), (pos.0 + 1, pos.1 + 1)]
+            .into_iter()
+            .map(|pos| dfsv2(pos, matrix, memo))
+            .sum(),
+        _ => panic!("disco!"),
+    };
+    memo.insert(pos, total);
+    total
+}
+
+/// The manifold and where the beam enters it.
+#[derive(Debug)]
+pub struct Manifold {
+    matrix: Matrix<char>,
+    start: Coord,
+}
+
+fn parse(input: &str) -> Manifold {
+    let matrix = Matrix::from(input, |c| c);
+    let start = matrix.find(&'S').unwrap();
+    Manifold { matrix, start }
+}
+
+fn part1(manifold: &Manifold) -> usize {
+    let mut visited: HashSet<Coord> = HashSet::new();
+    dfs(manifold.start, &mut visited, &manifold.matrix)
+}
+
+fn part2(manifold: &Manifold) -> usize {
+    dfsv2(manifold.start, &manifold.matrix, &mut HashMap::new())
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Parsed<'a> = Manifold;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const DAY: u8 = 7;
+
+    fn parse(input: &str) -> Manifold {
+        parse(input)
+    }
+
+    fn part1(manifold: &Manifold) -> usize {
+        part1(manifold)
+    }
+
+    fn part2(manifold: &Manifold) -> usize {
+        part2(manifold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+
+    #[test]
+    fn test_solution() {
+        let manifold = parse(&example(2025, 7, 1));
+        assert_eq!(part1(&manifold), example_answer(2025, 7, 1));
+        assert_eq!(part2(&manifold), example_answer(2025, 7, 2));
+    }
+}
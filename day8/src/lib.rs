@@ -0,0 +1,178 @@
+use common::dot::Dot;
+use common::graph::Graph;
+use common::solution::Solution;
+use itertools::Itertools;
+use std::collections::{HashMap, HashSet};
+
+type Coord = (usize, usize, usize);
+
+trait Distance {
+    fn distance(&self, other: &Self) -> f64;
+}
+
+impl Distance for Coord {
+    fn distance(&self, other: &Self) -> f64 {
+        let dx = self.0 as f64 - other.0 as f64;
+        let dy = self.1 as f64 - other.1 as f64;
+        let dz = self.2 as f64 - other.2 as f64;
+        (dx * dx + dy * dy + dz * dz).sqrt()
+    }
+}
+
+type Pair = (Coord, Coord, f64);
+
+/// Every pair of boxes with its distance, closest first.
+fn parse(input: &str) -> Vec<Pair> {
+    let input: Vec<Coord> = input
+        .lines()
+        .map(|line| {
+            line.split(",")
+                .map(|coord| coord.parse::<usize>().unwrap())
+                .collect::<Vec<_>>()
+        })
+        .map(|coords| (coords[0], coords[1], coords[2]))
+        .collect();
+    input
+        .iter()
+        .tuple_combinations()
+        .map(|(a, b)| (*a, *b, a.distance(b)))
+        .sorted_by(|i1, i2| i1.2.total_cmp(&i2.2))
+        .collect()
+}
+
+fn part1(distances: &[Pair], n: usize) -> usize {
+    // inputs with fewer pairs than asked for join all of them
+    let distances = &distances[..n.min(distances.len())];
+
+    let mut nodes: HashMap<Coord, HashSet<Coord>> = HashMap::new();
+    for (a, b, _) in distances {
+        let set_a = nodes.entry(*a).or_default().clone();
+        let set_b = nodes.entry(*b).or_default().clone();
+
+        let mut union: HashSet<Coord> = set_a.union(&set_b).cloned().collect();
+        union.insert(*a);
+        union.insert(*b);
+
+        for node in union.iter() {
+            nodes.insert(*node, union.clone());
+        }
+    }
+
+    let unique_sizes = nodes
+        .values()
+        .map(|set| {
+            let mut v: Vec<_> = set.iter().cloned().collect();
+            v.sort();
+            (v, set.len())
+        })
+        .unique_by(|(v, _)| v.clone())
+        .map(|(_, size)| size)
+        .sorted_by(|a, b| b.cmp(a))
+        .collect::<Vec<_>>();
+
+    unique_sizes.iter().take(3).product()
+}
+
+fn part2(distances: &[Pair]) -> usize {
+    let mut nodes: HashMap<Coord, HashSet<Coord>> = HashMap::new();
+    let mut multiply = 0;
+    for (a, b, _) in distances.iter() {
+        let set_a = nodes.entry(*a).or_default().clone();
+        let set_b = nodes.entry(*b).or_default().clone();
+        if set_a.contains(b) {
+            continue;
+        }
+        multiply = a.0 * b.0;
+
+        let mut union: HashSet<Coord> = set_a.union(&set_b).cloned().collect();
+        union.insert(*a);
+        union.insert(*b);
+
+        for node in union.iter() {
+            nodes.insert(*node, union.clone());
+        }
+    }
+
+    multiply
+}
+
+fn find(parent: &mut [usize], x: usize) -> usize {
+    if parent[x] != x {
+        parent[x] = find(parent, parent[x]);
+    }
+    parent[x]
+}
+
+/// The minimum spanning tree over all boxes with edges labelled by their
+/// rounded length, coloured by the circuits the first `n` pairs form.
+pub fn circuits_dot(input: &str, n: usize) -> String {
+    let distances = parse(input);
+    let name = |c: &Coord| format!("{},{},{}", c.0, c.1, c.2);
+    let mut mst = Graph::undirected();
+    let mut circuits = Graph::undirected();
+    for c in distances.iter().flat_map(|(a, b, _)| [a, b]) {
+        mst.intern(&name(c));
+        circuits.intern(&name(c));
+    }
+
+    let mut parent = (0..mst.len()).collect::<Vec<_>>();
+    for (rank, (a, b, distance)) in distances.iter().enumerate() {
+        let (a, b) = (mst.intern(&name(a)), mst.intern(&name(b)));
+        let (root_a, root_b) = (find(&mut parent, a), find(&mut parent, b));
+        if root_a == root_b {
+            continue;
+        }
+        parent[root_a] = root_b;
+        mst.add_edge(a, b, distance.round() as i64);
+        if rank < n {
+            circuits.add_edge(a, b, 1);
+        }
+    }
+    Dot::new(&mst)
+        .components(&circuits.sccs())
+        .weights()
+        .to_string()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Parsed<'a> = Vec<Pair>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    const DAY: u8 = 8;
+
+    fn parse(input: &str) -> Vec<Pair> {
+        parse(input)
+    }
+
+    fn part1(distances: &Vec<Pair>) -> usize {
+        part1(distances, 1000)
+    }
+
+    fn part2(distances: &Vec<Pair>) -> usize {
+        part2(distances)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+
+    #[test]
+    fn test_solution() {
+        let input = &example(2025, 8, 1);
+        let distances = parse(input);
+        // the example only joins its ten closest pairs
+        assert_eq!(part1(&distances, 10), example_answer(2025, 8, 1));
+        assert_eq!(part2(&distances), example_answer(2025, 8, 2));
+        // with all 190 pairs joined the twenty boxes form one circuit
+        assert_eq!(Day8::part1(&distances), 20);
+
+        let dot = circuits_dot(input, 10);
+        assert_eq!(dot.matches(" -- ").count(), 19);
+        assert_eq!(dot.matches("fillcolor").count(), 5 + 4 + 2 + 2);
+    }
+}
@@ -0,0 +1,69 @@
+use common::geometry::{get_size, Point};
+use common::polygon::Polygon;
+use common::solution::Solution;
+use itertools::Itertools;
+
+/// The red tiles in the order they are listed.
+fn parse(input: &str) -> Vec<Point> {
+    input
+        .lines()
+        .map(|line| line.split(',').collect_tuple().unwrap())
+        .map(|(a, b)| {
+            (a.parse::<isize>().unwrap(), b.parse::<isize>().unwrap())
+        })
+        .collect()
+}
+
+fn part1(corners: &[Point]) -> isize {
+    corners
+        .iter()
+        .tuple_combinations()
+        .map(|(&a, &b)| get_size(a, b))
+        .max()
+        .unwrap_or(0)
+}
+
+fn part2(corners: &[Point]) -> isize {
+    let polygon = Polygon::new(corners.to_vec())
+        .expect("red tiles must form a simple rectilinear loop")
+        .compress();
+
+    polygon
+        .largest_corner_rectangle(corners)
+        .map_or(0, |rect| rect.area())
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Parsed<'a> = Vec<Point>;
+    type Answer1 = isize;
+    type Answer2 = isize;
+
+    const DAY: u8 = 9;
+
+    fn parse(input: &str) -> Vec<Point> {
+        parse(input)
+    }
+
+    fn part1(corners: &Vec<Point>) -> isize {
+        part1(corners)
+    }
+
+    fn part2(corners: &Vec<Point>) -> isize {
+        part2(corners)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::input::{example, example_answer};
+
+    #[test]
+    fn test_solution() {
+        let corners = parse(&example(2025, 9, 1));
+        assert_eq!(part1(&corners), example_answer(2025, 9, 1));
+        assert_eq!(part2(&corners), example_answer(2025, 9, 2));
+    }
+}